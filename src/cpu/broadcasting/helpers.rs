@@ -32,3 +32,52 @@ pub fn get_broadcast_shape(shape_a: &[u32], shape_b: &[u32]) -> Option<Vec<u32>>
 
     Some(output_shape)
 }
+
+/// get broadcast shape of 2 shapes aligned to the right,
+/// so missing leading dimensions are treated as 1
+///
+/// # Example
+///
+/// ```
+/// use flashlight_tensor::cpu::broadcasting::helpers::{get_aligned_broadcast_shape};
+///
+/// let shape_a = vec!{4, 3};
+/// let shape_b = vec!{3};
+///
+/// let broadcast = get_aligned_broadcast_shape(&shape_a, &shape_b).unwrap();
+///
+/// assert_eq!(broadcast, vec!{4, 3});
+/// ```
+pub fn get_aligned_broadcast_shape(shape_a: &[u32], shape_b: &[u32]) -> Option<Vec<u32>>{
+    let rank = shape_a.len().max(shape_b.len());
+
+    let mut padded_a: Vec<u32> = vec![1; rank - shape_a.len()];
+    padded_a.extend_from_slice(shape_a);
+    let mut padded_b: Vec<u32> = vec![1; rank - shape_b.len()];
+    padded_b.extend_from_slice(shape_b);
+
+    get_broadcast_shape(&padded_a, &padded_b)
+}
+
+/// get position in tensor with shape, that is broadcast to output position
+/// with dimensions aligned to the right
+///
+/// # Example
+///
+/// ```
+/// use flashlight_tensor::cpu::broadcasting::helpers::{broadcast_position};
+///
+/// let position = broadcast_position(&[3, 2], &[1, 4]);
+///
+/// assert_eq!(position, vec!{0, 2});
+/// ```
+pub fn broadcast_position(output_position: &[u32], shape: &[u32]) -> Vec<u32>{
+    let offset = output_position.len() - shape.len();
+
+    let mut position: Vec<u32> = Vec::with_capacity(shape.len());
+    for i in 0..shape.len(){
+        position.push(output_position[offset + i] % shape[i]);
+    }
+
+    position
+}
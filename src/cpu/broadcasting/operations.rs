@@ -1,5 +1,5 @@
-use crate::tensor::{idx_to_global, Tensor};
-use crate::cpu::broadcasting::helpers::{get_broadcast_shape};
+use crate::tensor::{idx_to_global, split_axis, Tensor};
+use crate::cpu::broadcasting::helpers::{get_broadcast_shape, get_aligned_broadcast_shape, broadcast_position};
     
 
 impl<T> Tensor<T> 
@@ -179,3 +179,214 @@ where
         Tensor::from_data(&return_data, &broadcast_shape)
     }   
 }
+
+impl Tensor<f32>{
+    /// broadcast fused multiply add, self * mul + add
+    /// using f32::mul_add, so only one rounding step per element
+    /// shapes are broadcast from the right, like [features] onto [batch, features]
+    /// None if shapes are not broadcast compatible
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::fill(2.0, &[2, 2]);
+    /// let gamma: Tensor<f32> = Tensor::from_data(&[3.0, 4.0], &[1, 2]).unwrap();
+    /// let beta: Tensor<f32> = Tensor::from_data(&[1.0, -1.0], &[2, 1]).unwrap();
+    ///
+    /// let b: Tensor<f32> = a.fma(&gamma, &beta).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{7.0, 9.0, 5.0, 7.0});
+    /// assert_eq!(b.get_shape(), &vec!{2, 2});
+    /// ```
+    pub fn fma(&self, mul: &Tensor<f32>, add: &Tensor<f32>) -> Option<Tensor<f32>>{
+        let mul_shape = get_aligned_broadcast_shape(self.get_shape(), mul.get_shape())?;
+        let broadcast_shape = get_aligned_broadcast_shape(&mul_shape, add.get_shape())?;
+
+        let output_capacity = broadcast_shape.iter().product::<u32>();
+        let mut return_data = Vec::with_capacity(output_capacity as usize);
+
+        for i in 0..output_capacity{
+            let output_position = idx_to_global(i, &broadcast_shape);
+            let self_position = broadcast_position(&output_position, self.get_shape());
+            let mul_position = broadcast_position(&output_position, mul.get_shape());
+            let add_position = broadcast_position(&output_position, add.get_shape());
+
+            return_data.push(self.value(&self_position).unwrap().mul_add(*mul.value(&mul_position).unwrap(), *add.value(&add_position).unwrap()));
+        }
+        Tensor::from_data(&return_data, &broadcast_shape)
+    }
+
+    /// Computes scale * self + shift
+    /// with scale and shift broadcast from the right, like [features] onto [batch, features]
+    /// None if shapes can not be broadcast
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::fill(1.0, &[2, 2]);
+    /// let scale: Tensor<f32> = Tensor::from_data(&[2.0, 3.0], &[2]).unwrap();
+    /// let shift: Tensor<f32> = Tensor::from_data(&[0.5, -1.0], &[2]).unwrap();
+    ///
+    /// //b =
+    /// //[2.5, 2.0]
+    /// //[2.5, 2.0]
+    /// let b: Tensor<f32> = a.affine(&scale, &shift).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{2.5, 2.0, 2.5, 2.0});
+    /// ```
+    pub fn affine(&self, scale: &Tensor<f32>, shift: &Tensor<f32>) -> Option<Tensor<f32>>{
+        scale.fma(self, shift)
+    }
+
+    /// broadcast power, each element of first tensor raised
+    /// to the element of second tensor
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+    /// let b: Tensor<f32> = Tensor::from_data(&[2.0, 3.0], &[1, 2]).unwrap();
+    ///
+    /// let c: Tensor<f32> = a.pow_tensor(&b).unwrap();
+    ///
+    /// assert_eq!(c.get_data(), &vec!{1.0, 8.0, 9.0, 64.0});
+    /// assert_eq!(c.get_shape(), &vec!{2, 2});
+    /// ```
+    pub fn pow_tensor(&self, exponents: &Tensor<f32>) -> Option<Tensor<f32>>{
+        let broadcast_shape = get_broadcast_shape(self.get_shape(), exponents.get_shape())?;
+
+        let output_capacity = broadcast_shape.iter().product::<u32>();
+        let mut return_data = Vec::with_capacity(output_capacity as usize);
+
+        for i in 0..output_capacity{
+            let output_position = idx_to_global(i, &broadcast_shape);
+            let self_position: Vec<u32> = output_position.iter().zip(self.get_shape().iter())
+                .map(|(op, is)| op%is)
+                .collect();
+            let exponents_position: Vec<u32> = output_position.iter().zip(exponents.get_shape().iter())
+                .map(|(op, is)| op%is)
+                .collect();
+
+            return_data.push(self.value(&self_position).unwrap().powf(*exponents.value(&exponents_position).unwrap()));
+        }
+        Tensor::from_data(&return_data, &broadcast_shape)
+    }
+
+    /// Adds bias of shape [C] to every channel of tensor [C, H, W]
+    /// None if tensor is not 3D or bias length is not C
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::fill(1.0, &[2, 1, 2]);
+    /// let bias: Tensor<f32> = Tensor::from_data(&[1.0, -1.0], &[2]).unwrap();
+    ///
+    /// let b: Tensor<f32> = a.add_channel_bias(&bias).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{2.0, 2.0, 0.0, 0.0});
+    /// ```
+    pub fn add_channel_bias(&self, bias: &Tensor<f32>) -> Option<Tensor<f32>>{
+        if self.get_shape().len() != 3 || bias.get_shape().len() != 1{
+            return None;
+        }
+        if self.get_shape()[0] != bias.get_shape()[0]{
+            return None;
+        }
+
+        let channels = self.get_shape()[0] as usize;
+        let plane = (self.get_shape()[1] * self.get_shape()[2]) as usize;
+
+        let mut return_data: Vec<f32> = Vec::with_capacity(channels * plane);
+        for c in 0..channels{
+            let channel_bias = bias.get_data()[c];
+            for i in 0..plane{
+                return_data.push(self.get_data()[c*plane + i] + channel_bias);
+            }
+        }
+
+        Tensor::from_data(&return_data, self.get_shape())
+    }
+
+    /// Combines elements of two tensors with f, broadcasting shapes aligned to the right,
+    /// dimensions are compatible if equal or one of them is 1
+    /// None if shapes can not be broadcast
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::fill(1.0, &[4, 3]);
+    /// let row: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0], &[1, 3]).unwrap();
+    /// let col: Tensor<f32> = Tensor::from_data(&[10.0, 20.0, 30.0, 40.0], &[4, 1]).unwrap();
+    ///
+    /// let b: Tensor<f32> = a.broadcast_op(&row, |x, y| x + y).unwrap();
+    /// assert_eq!(&b.get_data()[0..6], &[2.0, 3.0, 4.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(b.get_shape(), &vec!{4, 3});
+    ///
+    /// let c: Tensor<f32> = a.broadcast_op(&col, |x, y| x * y).unwrap();
+    /// assert_eq!(&c.get_data()[0..6], &[10.0, 10.0, 10.0, 20.0, 20.0, 20.0]);
+    /// assert_eq!(c.get_shape(), &vec!{4, 3});
+    ///
+    /// let wrong: Tensor<f32> = Tensor::fill(1.0, &[2, 3]);
+    /// assert!(a.broadcast_op(&wrong, |x, y| x + y).is_none());
+    /// ```
+    pub fn broadcast_op(&self, other: &Tensor<f32>, f: impl Fn(f32, f32) -> f32) -> Option<Tensor<f32>>{
+        let broadcast_shape = get_aligned_broadcast_shape(self.get_shape(), other.get_shape())?;
+
+        let output_capacity = broadcast_shape.iter().product::<u32>();
+        let mut return_data = Vec::with_capacity(output_capacity as usize);
+
+        for i in 0..output_capacity{
+            let output_position = idx_to_global(i, &broadcast_shape);
+            let self_position = broadcast_position(&output_position, self.get_shape());
+            let other_position = broadcast_position(&output_position, other.get_shape());
+
+            return_data.push(f(*self.value(&self_position).unwrap(), *other.value(&other_position).unwrap()));
+        }
+        Tensor::from_data(&return_data, &broadcast_shape)
+    }
+}
+
+impl<T: Default + Clone> Tensor<T>{
+    /// Repeats axis of size 1 up to length
+    /// None if axis is out of range or its size is not 1
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0], &[1, 2]).unwrap();
+    ///
+    /// //b =
+    /// //[1.0, 2.0]
+    /// //[1.0, 2.0]
+    /// //[1.0, 2.0]
+    /// let b: Tensor<f32> = a.broadcast_to_length(0, 3).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{1.0, 2.0, 1.0, 2.0, 1.0, 2.0});
+    /// assert_eq!(b.get_shape(), &vec!{3, 2});
+    /// ```
+    pub fn broadcast_to_length(&self, axis: u32, length: u32) -> Option<Tensor<T>>{
+        let (outer, axis_length, inner) = split_axis(self.get_shape(), axis)?;
+        if axis_length != 1{
+            return None;
+        }
+
+        let mut return_data: Vec<T> = Vec::with_capacity((outer * length * inner) as usize);
+        for o in 0..outer{
+            let slice = &self.get_data()[(o*inner) as usize..((o+1)*inner) as usize];
+            for _ in 0..length{
+                return_data.extend_from_slice(slice);
+            }
+        }
+
+        let mut output_shape = self.get_shape().clone();
+        output_shape[axis as usize] = length;
+
+        Tensor::from_data(&return_data, &output_shape)
+    }
+}
@@ -0,0 +1,79 @@
+use crate::tensor::Tensor;
+
+impl Tensor<f32>{
+    /// Returns softmax(q * k^T / sqrt(d)) * v
+    /// for 2 dimensional tensors q[seq_q, d], k[seq_k, d], v[seq_k, d_v]
+    /// Mask of shape [seq_q, seq_k], true blocks query from attending to key
+    /// None if shapes does not match
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let q: Tensor<f32> = Tensor::fill(1.0, &[2, 4]);
+    /// let k: Tensor<f32> = Tensor::fill(1.0, &[3, 4]);
+    /// let v: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[3, 2]).unwrap();
+    ///
+    /// //all scores are equal, so each row is a mean of v rows
+    /// //result =
+    /// //[3.0, 4.0]
+    /// //[3.0, 4.0]
+    /// let result = Tensor::scaled_dot_product_attention(&q, &k, &v, None).unwrap();
+    ///
+    /// assert_eq!(result.get_shape(), &vec!{2, 2});
+    /// assert!((result.get_data()[0] - 3.0).abs() < 1e-5);
+    /// assert!((result.get_data()[1] - 4.0).abs() < 1e-5);
+    /// ```
+    pub fn scaled_dot_product_attention(q: &Tensor<f32>, k: &Tensor<f32>, v: &Tensor<f32>, mask: Option<&Tensor<bool>>) -> Option<Tensor<f32>>{
+        if q.get_shape().len() != 2 || k.get_shape().len() != 2 || v.get_shape().len() != 2{
+            return None;
+        }
+        if q.get_shape()[1] != k.get_shape()[1] || k.get_shape()[0] != v.get_shape()[0]{
+            return None;
+        }
+
+        let q_len = q.get_shape()[0];
+        let k_len = k.get_shape()[0];
+
+        if let Some(mask) = mask && mask.get_shape() != &vec!{q_len, k_len}{
+            return None;
+        }
+
+        let scale = 1.0 / (q.get_shape()[1] as f32).sqrt();
+        let scores = q.matrix_mul(&k.matrix_transpose()?)?.mul(scale);
+
+        let mut weights: Vec<f32> = scores.get_data().clone();
+
+        for row in 0..q_len as usize{
+            let begin = row * k_len as usize;
+            let end = begin + k_len as usize;
+
+            if let Some(mask) = mask{
+                for (weight, masked) in weights[begin..end].iter_mut().zip(&mask.get_data()[begin..end]){
+                    if *masked{
+                        *weight = f32::NEG_INFINITY;
+                    }
+                }
+            }
+
+            let max = weights[begin..end].iter().copied().fold(f32::NEG_INFINITY, f32::max);
+
+            //fully masked row attends to nothing
+            if max == f32::NEG_INFINITY{
+                weights[begin..end].iter_mut().for_each(|weight| *weight = 0.0);
+                continue;
+            }
+
+            let mut sum = 0.0;
+            for weight in weights[begin..end].iter_mut(){
+                *weight = (*weight - max).exp();
+                sum += *weight;
+            }
+            for weight in weights[begin..end].iter_mut(){
+                *weight /= sum;
+            }
+        }
+
+        Tensor::from_data(&weights, &[q_len, k_len])?.matrix_mul(v)
+    }
+}
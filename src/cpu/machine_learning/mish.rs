@@ -0,0 +1,27 @@
+use crate::tensor::Tensor;
+use crate::cpu::machine_learning::sigmoid::softplus;
+
+fn mish(x: f32) -> f32{
+    x * softplus(x).tanh()
+}
+
+impl Tensor<f32>{
+    /// Returns a tensor with data transformed using mish function x * tanh(softplus(x))
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[-200.0, 0.0, 200.0], &[3]).unwrap();
+    /// let b = a.mish();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{-0.0, 0.0, 200.0});
+    /// ```
+    pub fn mish(&self) -> Tensor<f32>{
+        let data_vec: Vec<f32> = self.get_data().iter()
+            .map(|val| mish(*val))
+            .collect();
+
+        Tensor::from_data(&data_vec, self.get_shape()).unwrap()
+    }
+}
@@ -1,2 +1,9 @@
 pub mod relu;
 pub mod sigmoid;
+pub mod attention;
+pub mod positional_encoding;
+pub mod pooling;
+pub mod softmax;
+pub mod tanh;
+pub mod silu;
+pub mod mish;
@@ -0,0 +1,82 @@
+use crate::tensor::Tensor;
+
+impl Tensor<f32>{
+    /// Returns matrix with average of each pool_h x pool_w window moved by stride.
+    /// Output size along each axis follows ceil mode, ceil((size - pool) / stride) + 1,
+    /// dropping a last window that would start at or past the edge.
+    /// Windows that go out of the matrix on the bottom or right edge are padded with zeros,
+    /// count_include_pad decides if padded elements count toward the denominator
+    /// None if tensor is not a matrix, or pool or stride is 0
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]).unwrap();
+    ///
+    /// //b = [3.0, 4.5]
+    /// let b: Tensor<f32> = a.avg_pool2d(2, 2, 2, false).unwrap();
+    /// //c = [3.0, 2.25]
+    /// let c: Tensor<f32> = a.avg_pool2d(2, 2, 2, true).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{3.0, 4.5});
+    /// assert_eq!(c.get_data(), &vec!{3.0, 2.25});
+    /// assert_eq!(c.get_shape(), &vec!{1, 2});
+    /// ```
+    pub fn avg_pool2d(&self, pool_h: u32, pool_w: u32, stride: u32, count_include_pad: bool) -> Option<Tensor<f32>>{
+        if self.get_shape().len() != 2{
+            return None;
+        }
+        if pool_h == 0 || pool_w == 0 || stride == 0{
+            return None;
+        }
+
+        let rows = self.get_shape()[0];
+        let cols = self.get_shape()[1];
+
+        let output_rows = pool_window_count(rows, pool_h, stride);
+        let output_cols = pool_window_count(cols, pool_w, stride);
+
+        let mut return_data: Vec<f32> = Vec::with_capacity((output_rows * output_cols) as usize);
+
+        for out_row in 0..output_rows{
+            for out_col in 0..output_cols{
+                let row_begin = out_row * stride;
+                let col_begin = out_col * stride;
+                let row_end = (row_begin + pool_h).min(rows);
+                let col_end = (col_begin + pool_w).min(cols);
+
+                let mut sum: f32 = 0.0;
+                for row in row_begin..row_end{
+                    for col in col_begin..col_end{
+                        sum += self.get_data()[(row*cols + col) as usize];
+                    }
+                }
+
+                let count = if count_include_pad{
+                    pool_h * pool_w
+                }
+                else{
+                    (row_end - row_begin) * (col_end - col_begin)
+                };
+
+                return_data.push(sum / count as f32);
+            }
+        }
+
+        Tensor::from_data(&return_data, &[output_rows, output_cols])
+    }
+}
+
+//ceil mode window count: ceil((size - pool) / stride) + 1,
+//without a last window that would start at or past the edge
+fn pool_window_count(size: u32, pool: u32, stride: u32) -> u32{
+    let windows = size.saturating_sub(pool).div_ceil(stride) + 1;
+
+    if (windows - 1) * stride >= size{
+        windows - 1
+    }
+    else{
+        windows
+    }
+}
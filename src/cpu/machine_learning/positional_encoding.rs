@@ -0,0 +1,41 @@
+use crate::tensor::Tensor;
+
+impl Tensor<f32>{
+    /// Returns sinusoidal positional encoding matrix with shape [seq_len, d_model]
+    /// pe[pos, 2i] = sin(pos / 10000^(2i/d_model))
+    /// pe[pos, 2i+1] = cos(pos / 10000^(2i/d_model))
+    /// None if d_model is odd or zero
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// //a =
+    /// //[0.0, 1.0, 0.0, 1.0]
+    /// //[sin(1), cos(1), sin(0.01), cos(0.01)]
+    /// let a: Tensor<f32> = Tensor::sinusoidal_positional_encoding(2, 4).unwrap();
+    ///
+    /// assert_eq!(a.get_shape(), &vec!{2, 4});
+    /// assert_eq!(a.get_data()[0..4], [0.0, 1.0, 0.0, 1.0]);
+    /// assert!((a.value(&[1, 0]).unwrap() - 1.0f32.sin()).abs() < 1e-6);
+    /// ```
+    pub fn sinusoidal_positional_encoding(seq_len: u32, d_model: u32) -> Option<Tensor<f32>>{
+        if d_model == 0 || !d_model.is_multiple_of(2){
+            return None;
+        }
+
+        let mut return_data: Vec<f32> = Vec::with_capacity((seq_len * d_model) as usize);
+
+        for pos in 0..seq_len{
+            for i in 0..d_model/2{
+                let frequency = 10000f32.powf((2 * i) as f32 / d_model as f32);
+                let angle = pos as f32 / frequency;
+
+                return_data.push(angle.sin());
+                return_data.push(angle.cos());
+            }
+        }
+
+        Tensor::from_data(&return_data, &[seq_len, d_model])
+    }
+}
@@ -1,11 +1,19 @@
 use crate::tensor::Tensor;
 
-fn sigmoid(x: f32) -> f32{
+pub(crate) fn sigmoid(x: f32) -> f32{
     1.0 / (1.0 + (-x).exp())
 }
 fn sigmoid_der(x: f32) -> f32{
     sigmoid(x) * (1.0 - sigmoid(x))
 }
+/// ln(1 + e^x), written so e^x never overflows
+pub(crate) fn softplus(x: f32) -> f32{
+    x.max(0.0) + (-x.abs()).exp().ln_1p()
+}
+/// ln(sigmoid(x)) = -ln(1 + e^-x) = -softplus(-x)
+fn log_sigmoid(x: f32) -> f32{
+    -softplus(-x)
+}
 
 impl Tensor<f32>{
     /// Returns a tensor with data transformed using sigmoid function
@@ -45,4 +53,24 @@ impl Tensor<f32>{
 
         Tensor::from_data(&data_vec, self.get_shape()).unwrap()
     }
+
+    /// Returns a tensor with data transformed using logarithm of sigmoid function,
+    /// stable for large negative values
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[-200.0, 0.0, 200.0], &[3]).unwrap();
+    /// let b = a.log_sigmoid();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{-200.0, -(2.0_f32.ln()), 0.0});
+    /// ```
+    pub fn log_sigmoid(&self) -> Tensor<f32>{
+        let data_vec: Vec<f32> = self.get_data().iter()
+            .map(|val| log_sigmoid(*val))
+            .collect();
+
+        Tensor::from_data(&data_vec, self.get_shape()).unwrap()
+    }
 }
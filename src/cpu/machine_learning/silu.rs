@@ -0,0 +1,50 @@
+use crate::tensor::Tensor;
+use crate::cpu::machine_learning::sigmoid::sigmoid;
+
+fn silu(x: f32) -> f32{
+    x * sigmoid(x)
+}
+fn silu_der(x: f32) -> f32{
+    let s = sigmoid(x);
+    s * (1.0 + x * (1.0 - s))
+}
+
+impl Tensor<f32>{
+    /// Returns a tensor with data transformed using SiLU (swish) function x * sigmoid(x)
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[-200.0, 0.0, 200.0], &[3]).unwrap();
+    /// let b = a.silu();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{-0.0, 0.0, 200.0});
+    /// ```
+    pub fn silu(&self) -> Tensor<f32>{
+        let data_vec: Vec<f32> = self.get_data().iter()
+            .map(|val| silu(*val))
+            .collect();
+
+        Tensor::from_data(&data_vec, self.get_shape()).unwrap()
+    }
+
+    /// Returns a tensor with data transformed using derivative of SiLU function
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[-200.0, 0.0, 200.0], &[3]).unwrap();
+    /// let b = a.silu_der();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{0.0, 0.5, 1.0});
+    /// ```
+    pub fn silu_der(&self) -> Tensor<f32>{
+        let data_vec: Vec<f32> = self.get_data().iter()
+            .map(|val| silu_der(*val))
+            .collect();
+
+        Tensor::from_data(&data_vec, self.get_shape()).unwrap()
+    }
+}
@@ -0,0 +1,144 @@
+use crate::tensor::*;
+
+impl Tensor<f32>{
+    /// Softmax along axis of logits divided by temperature
+    /// low temperature sharpens distribution, high temperature flattens it
+    /// None if axis is out of range or temperature is not positive
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[2.0_f32.ln(), 0.0], &[2]).unwrap();
+    ///
+    /// //b = [0.8, 0.2]
+    /// let b: Tensor<f32> = a.softmax_axis_t(0, 0.5).unwrap();
+    ///
+    /// assert!((b.get_data()[0] - 0.8).abs() < 1e-6);
+    /// assert!((b.get_data()[1] - 0.2).abs() < 1e-6);
+    /// ```
+    pub fn softmax_axis_t(&self, axis: u32, temperature: f32) -> Option<Tensor<f32>>{
+        if temperature.is_nan() || temperature <= 0.0{
+            return None;
+        }
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+
+        let mut return_data: Vec<f32> = vec![0.0; self.get_data().len()];
+
+        for o in 0..outer{
+            for j in 0..inner{
+                let mut max = f32::NEG_INFINITY;
+                for i in 0..length{
+                    let id = (o*length*inner + i*inner + j) as usize;
+                    max = max.max(self.get_data()[id] / temperature);
+                }
+
+                let mut sum: f32 = 0.0;
+                for i in 0..length{
+                    let id = (o*length*inner + i*inner + j) as usize;
+                    let value = (self.get_data()[id] / temperature - max).exp();
+                    return_data[id] = value;
+                    sum += value;
+                }
+
+                for i in 0..length{
+                    let id = (o*length*inner + i*inner + j) as usize;
+                    return_data[id] /= sum;
+                }
+            }
+        }
+
+        Tensor::from_data(&return_data, self.get_shape())
+    }
+
+    /// Softmax along axis,
+    /// max of each slice is subtracted before exponent for numerical stability
+    /// None if axis is out of range
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 1000.0, 1000.0, 1000.0], &[2, 3]).unwrap();
+    ///
+    /// let b: Tensor<f32> = a.softmax(1).unwrap();
+    ///
+    /// let first_row: f32 = b.get_data()[0..3].iter().sum();
+    /// let second_row: f32 = b.get_data()[3..6].iter().sum();
+    /// assert!((first_row - 1.0).abs() < 1e-6);
+    /// assert!((second_row - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn softmax(&self, axis: u32) -> Option<Tensor<f32>>{
+        self.softmax_axis_t(axis, 1.0)
+    }
+
+    /// Logarithm of softmax along axis,
+    /// computed as x - max - ln(sum(exp(x - max))) for numerical stability
+    /// None if axis is out of range
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[0.0, 0.0], &[1, 2]).unwrap();
+    ///
+    /// //b = [ln(0.5), ln(0.5)]
+    /// let b: Tensor<f32> = a.log_softmax(1).unwrap();
+    ///
+    /// assert!((b.get_data()[0] - 0.5_f32.ln()).abs() < 1e-6);
+    /// ```
+    pub fn log_softmax(&self, axis: u32) -> Option<Tensor<f32>>{
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+
+        let mut return_data: Vec<f32> = vec![0.0; self.get_data().len()];
+
+        for o in 0..outer{
+            for j in 0..inner{
+                let mut max = f32::NEG_INFINITY;
+                for i in 0..length{
+                    let id = (o*length*inner + i*inner + j) as usize;
+                    max = max.max(self.get_data()[id]);
+                }
+
+                let mut sum: f32 = 0.0;
+                for i in 0..length{
+                    let id = (o*length*inner + i*inner + j) as usize;
+                    sum += (self.get_data()[id] - max).exp();
+                }
+
+                let log_sum = sum.ln();
+                for i in 0..length{
+                    let id = (o*length*inner + i*inner + j) as usize;
+                    return_data[id] = self.get_data()[id] - max - log_sum;
+                }
+            }
+        }
+
+        Tensor::from_data(&return_data, self.get_shape())
+    }
+
+    /// Divides all logits by temperature in place
+    /// false if temperature is not positive, tensor is left unchanged
+    ///
+    /// !Mutates a tensor
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let mut a: Tensor<f32> = Tensor::from_data(&[1.0, -2.0, 4.0], &[3]).unwrap();
+    ///
+    /// assert!(a.scale_temperature_inplace(0.5));
+    ///
+    /// assert_eq!(a.get_data(), &vec!{2.0, -4.0, 8.0});
+    /// ```
+    pub fn scale_temperature_inplace(&mut self, temperature: f32) -> bool{
+        if temperature.is_nan() || temperature <= 0.0{
+            return false;
+        }
+
+        self.map_inplace(|x| *x /= temperature);
+
+        true
+    }
+}
@@ -0,0 +1,77 @@
+use crate::tensor::Tensor;
+
+fn tanh_der(x: f32) -> f32{
+    1.0 - x.tanh() * x.tanh()
+}
+
+impl Tensor<f32>{
+    /// Returns a tensor with data transformed using tanh function
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[-200.0, 0.0, 0.5, 200.0], &[4]).unwrap();
+    /// let b = a.tanh_activation();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{-1.0, 0.0, 0.5_f32.tanh(), 1.0});
+    /// ```
+    pub fn tanh_activation(&self) -> Tensor<f32>{
+        let data_vec: Vec<f32> = self.get_data().iter()
+            .map(|val| val.tanh())
+            .collect();
+
+        Tensor::from_data(&data_vec, self.get_shape()).unwrap()
+    }
+
+    /// Returns a tensor with data transformed using derivative of tanh function
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[-200.0, 0.0, 200.0, 0.0], &[4]).unwrap();
+    /// let b = a.tanh_der();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{0.0, 1.0, 0.0, 1.0});
+    /// ```
+    pub fn tanh_der(&self) -> Tensor<f32>{
+        let data_vec: Vec<f32> = self.get_data().iter()
+            .map(|val| tanh_der(*val))
+            .collect();
+
+        Tensor::from_data(&data_vec, self.get_shape()).unwrap()
+    }
+
+    /// Returns a tensor with data transformed using hard tanh function,
+    /// identity between min and max, saturating to min and max outside
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[-3.0, -0.5, 0.5, 3.0], &[4]).unwrap();
+    /// let b = a.hard_tanh(-1.0, 1.0);
+    ///
+    /// assert_eq!(b.get_data(), &vec!{-1.0, -0.5, 0.5, 1.0});
+    /// ```
+    pub fn hard_tanh(&self, min: f32, max: f32) -> Tensor<f32>{
+        self.map(|val| val.max(min).min(max))
+    }
+
+    /// Returns a tensor with data transformed using derivative of hard tanh function,
+    /// 1.0 between min and max, 0.0 outside
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[-3.0, -0.5, 0.5, 3.0], &[4]).unwrap();
+    /// let b = a.hard_tanh_derivative(-1.0, 1.0);
+    ///
+    /// assert_eq!(b.get_data(), &vec!{0.0, 1.0, 1.0, 0.0});
+    /// ```
+    pub fn hard_tanh_derivative(&self, min: f32, max: f32) -> Tensor<f32>{
+        self.map(|val| if *val > min && *val < max { 1.0 } else { 0.0 })
+    }
+}
@@ -0,0 +1,104 @@
+use crate::tensor::*;
+
+impl<T: Default + Clone> Tensor<T>{
+    /// Joins tensors along axis,
+    /// all tensors need same rank and same sizes on every other axis
+    /// None if tensors is empty or shapes do not match
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+    /// let b: Tensor<f32> = Tensor::from_data(&[5.0, 6.0, 7.0, 8.0], &[2, 2]).unwrap();
+    ///
+    /// //rows =
+    /// //[1.0, 2.0]
+    /// //[3.0, 4.0]
+    /// //[5.0, 6.0]
+    /// //[7.0, 8.0]
+    /// let rows: Tensor<f32> = Tensor::concat(&[a.clone(), b.clone()], 0).unwrap();
+    ///
+    /// assert_eq!(rows.get_data(), &vec!{1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0});
+    /// assert_eq!(rows.get_shape(), &vec!{4, 2});
+    ///
+    /// //cols =
+    /// //[1.0, 2.0, 5.0, 6.0]
+    /// //[3.0, 4.0, 7.0, 8.0]
+    /// let cols: Tensor<f32> = Tensor::concat(&[a, b], 1).unwrap();
+    ///
+    /// assert_eq!(cols.get_data(), &vec!{1.0, 2.0, 5.0, 6.0, 3.0, 4.0, 7.0, 8.0});
+    /// assert_eq!(cols.get_shape(), &vec!{2, 4});
+    /// ```
+    pub fn concat(tensors: &[Tensor<T>], axis: u32) -> Option<Tensor<T>>{
+        let first = tensors.first()?;
+        let (outer, _, inner) = split_axis(first.get_shape(), axis)?;
+
+        let mut axis_length: u32 = 0;
+        for tensor in tensors{
+            let shape = tensor.get_shape();
+            if shape.len() != first.get_shape().len(){
+                return None;
+            }
+            for (k, (dim, first_dim)) in shape.iter().zip(first.get_shape()).enumerate(){
+                if k != axis as usize && dim != first_dim{
+                    return None;
+                }
+            }
+            axis_length += shape[axis as usize];
+        }
+
+        let mut return_data: Vec<T> = Vec::with_capacity((outer * axis_length * inner) as usize);
+        for o in 0..outer{
+            for tensor in tensors{
+                let chunk = (tensor.get_shape()[axis as usize] * inner) as usize;
+                return_data.extend_from_slice(&tensor.get_data()[o as usize * chunk..(o as usize + 1) * chunk]);
+            }
+        }
+
+        let mut output_shape = first.get_shape().clone();
+        output_shape[axis as usize] = axis_length;
+
+        Tensor::from_data(&return_data, &output_shape)
+    }
+
+    /// Stacks tensors of identical shape along new axis of size tensors.len()
+    /// None if tensors is empty, shapes differ or axis is bigger than number of dimensions
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0], &[2]).unwrap();
+    /// let b: Tensor<f32> = Tensor::from_data(&[3.0, 4.0], &[2]).unwrap();
+    ///
+    /// //rows =
+    /// //[1.0, 2.0]
+    /// //[3.0, 4.0]
+    /// let rows: Tensor<f32> = Tensor::stack(&[a.clone(), b.clone()], 0).unwrap();
+    ///
+    /// assert_eq!(rows.get_data(), &vec!{1.0, 2.0, 3.0, 4.0});
+    /// assert_eq!(rows.get_shape(), &vec!{2, 2});
+    ///
+    /// //cols =
+    /// //[1.0, 3.0]
+    /// //[2.0, 4.0]
+    /// let cols: Tensor<f32> = Tensor::stack(&[a, b], 1).unwrap();
+    ///
+    /// assert_eq!(cols.get_data(), &vec!{1.0, 3.0, 2.0, 4.0});
+    /// assert_eq!(cols.get_shape(), &vec!{2, 2});
+    /// ```
+    pub fn stack(tensors: &[Tensor<T>], axis: u32) -> Option<Tensor<T>>{
+        let first = tensors.first()?;
+
+        let mut expanded: Vec<Tensor<T>> = Vec::with_capacity(tensors.len());
+        for tensor in tensors{
+            if tensor.get_shape() != first.get_shape(){
+                return None;
+            }
+            expanded.push(tensor.unsqueeze(axis)?);
+        }
+
+        Tensor::concat(&expanded, axis)
+    }
+}
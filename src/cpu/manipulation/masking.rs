@@ -0,0 +1,35 @@
+use crate::tensor::*;
+
+impl<T: Default + Clone> Tensor<T>{
+    /// Returns vector of elements, where mask is true
+    /// in row-major order
+    /// None if mask has different shape
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+    /// let mask: Tensor<bool> = Tensor::from_data(&[true, false, false, true], &[2, 2]).unwrap();
+    ///
+    /// //b = [1.0, 4.0]
+    /// let b: Tensor<f32> = a.masked_select(&mask).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{1.0, 4.0});
+    /// assert_eq!(b.get_shape(), &vec!{2});
+    /// ```
+    pub fn masked_select(&self, mask: &Tensor<bool>) -> Option<Tensor<T>>{
+        if self.get_shape() != mask.get_shape(){
+            return None;
+        }
+
+        let return_data: Vec<T> = self.get_data().iter()
+            .zip(mask.get_data().iter())
+            .filter(|(_, keep)| **keep)
+            .map(|(val, _)| val.clone())
+            .collect();
+
+        let length = return_data.len() as u32;
+        Tensor::from_data(&return_data, &[length])
+    }
+}
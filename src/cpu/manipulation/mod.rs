@@ -0,0 +1,6 @@
+pub mod roll;
+pub mod padding;
+pub mod masking;
+pub mod permute;
+pub mod concat;
+pub mod slice;
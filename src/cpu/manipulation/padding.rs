@@ -0,0 +1,174 @@
+use crate::tensor::*;
+
+/// How to fill the padded elements
+#[derive(Debug, Clone, PartialEq)]
+pub enum PadMode<T>{
+    /// fill with constant value
+    Constant(T),
+    /// mirror values across the edge, without repeating the edge
+    Reflect,
+    /// repeat the edge value
+    Replicate,
+}
+
+impl<T: Default + Clone> Tensor<T>{
+    /// Pads tensor with before[i] elements at the beginning
+    /// and after[i] elements at the end of each axis i
+    /// None if before or after length differ from rank,
+    /// or reflect pad is not smaller than axis length
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0], &[3]).unwrap();
+    ///
+    /// let constant = a.pad_mode(&[1], &[1], PadMode::Constant(0.0)).unwrap();
+    /// let reflect = a.pad_mode(&[2], &[1], PadMode::Reflect).unwrap();
+    /// let replicate = a.pad_mode(&[2], &[1], PadMode::Replicate).unwrap();
+    ///
+    /// assert_eq!(constant.get_data(), &vec!{0.0, 1.0, 2.0, 3.0, 0.0});
+    /// assert_eq!(reflect.get_data(), &vec!{3.0, 2.0, 1.0, 2.0, 3.0, 2.0});
+    /// assert_eq!(replicate.get_data(), &vec!{1.0, 1.0, 1.0, 2.0, 3.0, 3.0});
+    /// ```
+    pub fn pad_mode(&self, before: &[u32], after: &[u32], mode: PadMode<T>) -> Option<Tensor<T>>{
+        let rank = self.get_shape().len();
+        if before.len() != rank || after.len() != rank{
+            return None;
+        }
+
+        for axis in 0..rank{
+            let length = self.get_shape()[axis];
+            match mode{
+                PadMode::Reflect => {
+                    if before[axis] >= length.max(1) || after[axis] >= length.max(1){
+                        return None;
+                    }
+                }
+                PadMode::Replicate => {
+                    if length == 0 && before[axis] + after[axis] > 0{
+                        return None;
+                    }
+                }
+                PadMode::Constant(_) => {}
+            }
+        }
+
+        let output_shape: Vec<u32> = (0..rank)
+            .map(|axis| before[axis] + self.get_shape()[axis] + after[axis])
+            .collect();
+        let output_capacity: u32 = output_shape.iter().product();
+
+        let mut return_data: Vec<T> = Vec::with_capacity(output_capacity as usize);
+
+        for i in 0..output_capacity{
+            let output_position = idx_to_global(i, &output_shape);
+
+            let mut source_position: Vec<u32> = Vec::with_capacity(rank);
+            let mut inside = true;
+
+            for axis in 0..rank{
+                let length = self.get_shape()[axis] as i64;
+                let pos = output_position[axis] as i64 - before[axis] as i64;
+
+                if pos >= 0 && pos < length{
+                    source_position.push(pos as u32);
+                    continue;
+                }
+
+                match mode{
+                    PadMode::Constant(_) => {
+                        inside = false;
+                        break;
+                    }
+                    PadMode::Reflect => {
+                        let reflected = if pos < 0 {-pos} else {2 * (length - 1) - pos};
+                        source_position.push(reflected as u32);
+                    }
+                    PadMode::Replicate => {
+                        source_position.push(pos.clamp(0, length - 1) as u32);
+                    }
+                }
+            }
+
+            if inside{
+                return_data.push(self.value(&source_position).unwrap().clone());
+            }
+            else if let PadMode::Constant(value) = &mode{
+                return_data.push(value.clone());
+            }
+        }
+
+        Tensor::from_data(&return_data, &output_shape)
+    }
+}
+
+/// Pads sequences [len] or [len, d] with pad_value to the longest length
+/// and stacks them into batch [batch, max_len] or [batch, max_len, d],
+/// returns batch and mask [batch, max_len] with true on padded positions
+/// None if there are no sequences, ranks differ, or d differs
+///
+/// # Example
+/// ```
+/// use flashlight_tensor::prelude::*;
+///
+/// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0], &[3]).unwrap();
+/// let b: Tensor<f32> = Tensor::from_data(&[4.0], &[1]).unwrap();
+///
+/// //batch =
+/// //[1.0, 2.0, 3.0]
+/// //[4.0, 0.0, 0.0]
+/// let (batch, mask) = pad_sequences(&[&a, &b], 0.0).unwrap();
+///
+/// assert_eq!(batch.get_data(), &vec!{1.0, 2.0, 3.0, 4.0, 0.0, 0.0});
+/// assert_eq!(mask.get_data(), &vec!{false, false, false, false, true, true});
+/// ```
+pub fn pad_sequences(seqs: &[&Tensor<f32>], pad_value: f32) -> Option<(Tensor<f32>, Tensor<bool>)>{
+    if seqs.is_empty(){
+        return None;
+    }
+
+    let rank = seqs[0].get_shape().len();
+    if rank != 1 && rank != 2{
+        return None;
+    }
+    let mut features: u32 = 1;
+    if rank == 2{
+        features = seqs[0].get_shape()[1];
+    }
+
+    let mut max_len: u32 = 0;
+    for seq in seqs{
+        if seq.get_shape().len() != rank{
+            return None;
+        }
+        if rank == 2 && seq.get_shape()[1] != features{
+            return None;
+        }
+        max_len = max_len.max(seq.get_shape()[0]);
+    }
+
+    let batch = seqs.len() as u32;
+    let mut return_data: Vec<f32> = Vec::with_capacity((batch * max_len * features) as usize);
+    let mut mask_data: Vec<bool> = Vec::with_capacity((batch * max_len) as usize);
+
+    for seq in seqs{
+        let len = seq.get_shape()[0];
+        return_data.extend_from_slice(seq.get_data());
+        return_data.extend(std::iter::repeat_n(pad_value, ((max_len - len) * features) as usize));
+
+        for t in 0..max_len{
+            mask_data.push(t >= len);
+        }
+    }
+
+    let mut batch_shape: Vec<u32> = vec!{batch, max_len};
+    if rank == 2{
+        batch_shape.push(features);
+    }
+
+    Some((
+        Tensor::from_data(&return_data, &batch_shape)?,
+        Tensor::from_data(&mask_data, &[batch, max_len])?,
+    ))
+}
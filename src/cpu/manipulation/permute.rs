@@ -0,0 +1,56 @@
+use crate::tensor::*;
+
+impl<T: Default + Clone> Tensor<T>{
+    /// Reorders axes of tensor, axis i of output is axis order[i] of input
+    /// None if order is not permutation of axes
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let data: Vec<f32> = (0..24).map(|i| i as f32).collect();
+    /// let a: Tensor<f32> = Tensor::from_data(&data, &[2, 3, 4]).unwrap();
+    ///
+    /// let b: Tensor<f32> = a.permute(&[2, 0, 1]).unwrap();
+    ///
+    /// assert_eq!(b.get_shape(), &vec!{4, 2, 3});
+    /// //b[k, i, j] = a[i, j, k]
+    /// assert_eq!(b.value(&[3, 1, 2]), a.value(&[1, 2, 3]));
+    /// assert_eq!(&b.get_data()[0..6], &[0.0, 4.0, 8.0, 12.0, 16.0, 20.0]);
+    /// ```
+    pub fn permute(&self, order: &[u32]) -> Option<Tensor<T>>{
+        let rank = self.get_shape().len();
+        if order.len() != rank{
+            return None;
+        }
+
+        let mut used: Vec<bool> = vec![false; rank];
+        for axis in order{
+            let axis = *axis as usize;
+            if axis >= rank || used[axis]{
+                return None;
+            }
+            used[axis] = true;
+        }
+
+        let strides = self.strides();
+        let mut new_shape: Vec<u32> = Vec::with_capacity(rank);
+        for axis in order{
+            new_shape.push(self.get_shape()[*axis as usize]);
+        }
+
+        let mut return_data: Vec<T> = Vec::with_capacity(self.get_data().len());
+        for i in 0..self.get_data().len(){
+            let output_position = idx_to_global(i as u32, &new_shape);
+
+            let mut source_id: u32 = 0;
+            for k in 0..rank{
+                source_id += output_position[k] * strides[order[k] as usize];
+            }
+
+            return_data.push(self.get_data()[source_id as usize].clone());
+        }
+
+        Tensor::from_data(&return_data, &new_shape)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::tensor::*;
+
+impl<T: Default + Clone> Tensor<T>{
+    /// Cyclically shifts elements by shifts[i] along axes[i]
+    /// element that goes out of the end comes back at the beginning
+    /// None if lengths of shifts and axes differ, or axes are repeated or out of range
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+    ///
+    /// //b =
+    /// //[4.0, 3.0]
+    /// //[2.0, 1.0]
+    /// let b: Tensor<f32> = a.roll_multi(&[1, -1], &[0, 1]).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{4.0, 3.0, 2.0, 1.0});
+    /// ```
+    pub fn roll_multi(&self, shifts: &[i32], axes: &[u32]) -> Option<Tensor<T>>{
+        if shifts.len() != axes.len(){
+            return None;
+        }
+
+        let rank = self.get_shape().len();
+        let mut axis_shifts: Vec<i64> = vec![0; rank];
+        let mut used: Vec<bool> = vec![false; rank];
+
+        for i in 0..axes.len(){
+            let axis = axes[i] as usize;
+            if axis >= rank || used[axis]{
+                return None;
+            }
+            used[axis] = true;
+            axis_shifts[axis] = shifts[i] as i64;
+        }
+
+        let mut return_data: Vec<T> = Vec::with_capacity(self.get_data().len());
+
+        for i in 0..self.get_data().len(){
+            let output_position = self.idx_to_global(i as u32);
+            let source_position: Vec<u32> = output_position.iter().enumerate()
+                .map(|(axis, pos)| {
+                    let length = self.get_shape()[axis] as i64;
+                    (*pos as i64 - axis_shifts[axis]).rem_euclid(length) as u32
+                })
+                .collect();
+
+            return_data.push(self.value(&source_position).unwrap().clone());
+        }
+
+        Tensor::from_data(&return_data, self.get_shape())
+    }
+}
@@ -0,0 +1,65 @@
+use crate::tensor::*;
+
+impl<T: Default + Clone> Tensor<T>{
+    /// Copies part of tensor with range [start, end) on each axis,
+    /// axes without range are taken whole
+    /// None if there are more ranges than axes, or range is reversed or out of bounds
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let data: Vec<f32> = (0..16).map(|i| i as f32).collect();
+    /// let a: Tensor<f32> = Tensor::from_data(&data, &[4, 4]).unwrap();
+    ///
+    /// //b =
+    /// //[0.0, 1.0]
+    /// //[4.0, 5.0]
+    /// let b: Tensor<f32> = a.slice(&[(0, 2), (0, 2)]).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{0.0, 1.0, 4.0, 5.0});
+    /// assert_eq!(b.get_shape(), &vec!{2, 2});
+    ///
+    /// let data: Vec<f32> = (0..24).map(|i| i as f32).collect();
+    /// let c: Tensor<f32> = Tensor::from_data(&data, &[2, 3, 4]).unwrap();
+    ///
+    /// let d: Tensor<f32> = c.slice(&[(1, 2), (0, 3), (1, 3)]).unwrap();
+    ///
+    /// assert_eq!(d.get_data(), &vec!{13.0, 14.0, 17.0, 18.0, 21.0, 22.0});
+    /// assert_eq!(d.get_shape(), &vec!{1, 3, 2});
+    /// ```
+    pub fn slice(&self, ranges: &[(u32, u32)]) -> Option<Tensor<T>>{
+        let rank = self.get_shape().len();
+        if ranges.len() > rank{
+            return None;
+        }
+
+        let mut starts: Vec<u32> = vec![0; rank];
+        let mut new_shape: Vec<u32> = self.get_shape().clone();
+        for i in 0..ranges.len(){
+            let (start, end) = ranges[i];
+            if start > end || end > self.get_shape()[i]{
+                return None;
+            }
+            starts[i] = start;
+            new_shape[i] = end - start;
+        }
+
+        let strides = self.strides();
+        let output_size: u32 = new_shape.iter().product();
+
+        let mut return_data: Vec<T> = Vec::with_capacity(output_size as usize);
+        for i in 0..output_size{
+            let output_position = idx_to_global(i, &new_shape);
+
+            let mut source_id: u32 = 0;
+            for k in 0..rank{
+                source_id += (output_position[k] + starts[k]) * strides[k];
+            }
+
+            return_data.push(self.get_data()[source_id as usize].clone());
+        }
+
+        Tensor::from_data(&return_data, &new_shape)
+    }
+}
@@ -0,0 +1,168 @@
+use crate::tensor::*;
+use crate::cpu::math::reductions::reduced_shape;
+
+impl Tensor<f32>{
+    /// Returns difference x[i+1] - x[i] along axis
+    /// with the axis shorter by one
+    /// None if axis out of range or empty
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 3.0, 6.0, 10.0], &[4]).unwrap();
+    ///
+    /// //b = [2.0, 3.0, 4.0]
+    /// let b: Tensor<f32> = a.diff(0).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{2.0, 3.0, 4.0});
+    /// assert_eq!(b.get_shape(), &vec!{3});
+    /// ```
+    pub fn diff(&self, axis: u32) -> Option<Tensor<f32>>{
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+        if length == 0{
+            return None;
+        }
+
+        let mut return_data: Vec<f32> = Vec::with_capacity((outer * (length - 1) * inner) as usize);
+
+        for o in 0..outer{
+            for i in 0..length-1{
+                for j in 0..inner{
+                    let current = self.get_data()[(o*length*inner + i*inner + j) as usize];
+                    let next = self.get_data()[(o*length*inner + (i+1)*inner + j) as usize];
+                    return_data.push(next - current);
+                }
+            }
+        }
+
+        let mut output_shape = self.get_shape().clone();
+        output_shape[axis as usize] -= 1;
+
+        Tensor::from_data(&return_data, &output_shape)
+    }
+
+    /// Returns numerical gradient along axis divided by spacing,
+    /// central differences inside and one sided differences on the edges
+    /// None if axis out of range or shorter than 2
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 4.0, 7.0], &[4]).unwrap();
+    ///
+    /// //b = [1.0, 1.5, 2.5, 3.0]
+    /// let b: Tensor<f32> = a.gradient_axis(0, 1.0).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{1.0, 1.5, 2.5, 3.0});
+    /// ```
+    pub fn gradient_axis(&self, axis: u32, spacing: f32) -> Option<Tensor<f32>>{
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+        if length < 2{
+            return None;
+        }
+
+        let mut return_data: Vec<f32> = vec![0.0; self.get_data().len()];
+
+        for o in 0..outer{
+            for j in 0..inner{
+                let id = |i: u32| (o*length*inner + i*inner + j) as usize;
+
+                return_data[id(0)] = (self.get_data()[id(1)] - self.get_data()[id(0)]) / spacing;
+                for i in 1..length-1{
+                    return_data[id(i)] = (self.get_data()[id(i+1)] - self.get_data()[id(i-1)]) / (2.0 * spacing);
+                }
+                return_data[id(length-1)] = (self.get_data()[id(length-1)] - self.get_data()[id(length-2)]) / spacing;
+            }
+        }
+
+        Tensor::from_data(&return_data, self.get_shape())
+    }
+
+    /// Returns linear interpolation of points x
+    /// on function given by sorted sample points xp and values fp.
+    /// Points outside of xp are clamped to the edge values
+    /// None if xp and fp are not vectors of the same, non zero length
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let x: Tensor<f32> = Tensor::from_data(&[-1.0, 0.5, 1.5, 3.0], &[4]).unwrap();
+    /// let xp: Tensor<f32> = Tensor::from_data(&[0.0, 1.0, 2.0], &[3]).unwrap();
+    /// let fp: Tensor<f32> = Tensor::from_data(&[0.0, 10.0, 0.0], &[3]).unwrap();
+    ///
+    /// let result: Tensor<f32> = Tensor::interp(&x, &xp, &fp).unwrap();
+    ///
+    /// assert_eq!(result.get_data(), &vec!{0.0, 5.0, 5.0, 0.0});
+    /// ```
+    pub fn interp(x: &Tensor<f32>, xp: &Tensor<f32>, fp: &Tensor<f32>) -> Option<Tensor<f32>>{
+        if xp.get_shape().len() != 1 || xp.get_shape() != fp.get_shape(){
+            return None;
+        }
+        if xp.get_data().is_empty(){
+            return None;
+        }
+
+        let xp_data = xp.get_data();
+        let fp_data = fp.get_data();
+        let last = xp_data.len() - 1;
+
+        let mut return_data: Vec<f32> = Vec::with_capacity(x.get_data().len());
+
+        for point in x.get_data(){
+            if *point <= xp_data[0]{
+                return_data.push(fp_data[0]);
+                continue;
+            }
+            if *point >= xp_data[last]{
+                return_data.push(fp_data[last]);
+                continue;
+            }
+
+            let right = xp_data.partition_point(|val| val <= point);
+            let left = right - 1;
+
+            let t = (point - xp_data[left]) / (xp_data[right] - xp_data[left]);
+            return_data.push(fp_data[left] + t * (fp_data[right] - fp_data[left]));
+        }
+
+        Tensor::from_data(&return_data, x.get_shape())
+    }
+
+    /// Returns integral along axis using trapezoidal rule
+    /// with spacing dx, with the axis removed
+    /// None if axis out of range
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[0.0, 1.0, 2.0, 3.0], &[4]).unwrap();
+    ///
+    /// //b = [4.5]
+    /// let b: Tensor<f32> = a.trapz(0, 1.0).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{4.5});
+    /// ```
+    pub fn trapz(&self, axis: u32, dx: f32) -> Option<Tensor<f32>>{
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+
+        let mut return_data: Vec<f32> = Vec::with_capacity((outer * inner) as usize);
+
+        for o in 0..outer{
+            for j in 0..inner{
+                let mut sum: f32 = 0.0;
+                for i in 1..length{
+                    let previous = self.get_data()[(o*length*inner + (i-1)*inner + j) as usize];
+                    let current = self.get_data()[(o*length*inner + i*inner + j) as usize];
+                    sum += (previous + current) * 0.5 * dx;
+                }
+                return_data.push(sum);
+            }
+        }
+
+        Tensor::from_data(&return_data, &reduced_shape(self.get_shape(), axis))
+    }
+}
@@ -0,0 +1,168 @@
+use crate::tensor::*;
+
+impl<T> Tensor<T>
+where
+    T: Default + PartialOrd + Copy,
+{
+    /// Each element smaller than min is replaced with min
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[-1.0, 0.5, 2.0], &[3]).unwrap();
+    ///
+    /// //b = [0.0, 0.5, 2.0]
+    /// let b: Tensor<f32> = a.clamp_min(0.0);
+    ///
+    /// assert_eq!(b.get_data(), &vec!{0.0, 0.5, 2.0})
+    /// ```
+    pub fn clamp_min(&self, min: T) -> Tensor<T>{
+        let mut return_data = Vec::with_capacity(self.get_data().len());
+
+        for i in 0..self.get_data().len(){
+            let val = self.get_data()[i];
+            return_data.push(if val < min {min} else {val});
+        }
+
+        Tensor::from_data(&return_data, self.get_shape()).unwrap()
+    }
+    /// Each element smaller than min is replaced with min
+    ///
+    /// !Mutates the tensor
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let mut a: Tensor<f32> = Tensor::from_data(&[-1.0, 0.5, 2.0], &[3]).unwrap();
+    ///
+    /// //a = [0.0, 0.5, 2.0]
+    /// a.clamp_min_mut(0.0);
+    ///
+    /// assert_eq!(a.get_data(), &vec!{0.0, 0.5, 2.0})
+    /// ```
+    pub fn clamp_min_mut(&mut self, min: T){
+        let return_data = self.clamp_min(min);
+
+        self.set_data(return_data.get_data());
+    }
+    /// Each element bigger than max is replaced with max
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[-1.0, 0.5, 2.0], &[3]).unwrap();
+    ///
+    /// //b = [-1.0, 0.5, 1.0]
+    /// let b: Tensor<f32> = a.clamp_max(1.0);
+    ///
+    /// assert_eq!(b.get_data(), &vec!{-1.0, 0.5, 1.0})
+    /// ```
+    pub fn clamp_max(&self, max: T) -> Tensor<T>{
+        let mut return_data = Vec::with_capacity(self.get_data().len());
+
+        for i in 0..self.get_data().len(){
+            let val = self.get_data()[i];
+            return_data.push(if val > max {max} else {val});
+        }
+
+        Tensor::from_data(&return_data, self.get_shape()).unwrap()
+    }
+    /// Each element bigger than max is replaced with max
+    ///
+    /// !Mutates the tensor
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let mut a: Tensor<f32> = Tensor::from_data(&[-1.0, 0.5, 2.0], &[3]).unwrap();
+    ///
+    /// //a = [-1.0, 0.5, 1.0]
+    /// a.clamp_max_mut(1.0);
+    ///
+    /// assert_eq!(a.get_data(), &vec!{-1.0, 0.5, 1.0})
+    /// ```
+    pub fn clamp_max_mut(&mut self, max: T){
+        let return_data = self.clamp_max(max);
+
+        self.set_data(return_data.get_data());
+    }
+}
+
+impl Tensor<f32>{
+    /// Each element clamped between min and max,
+    /// returns clamped tensor and count of elements that were out of range
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[-2.0, 0.5, 3.0], &[3]).unwrap();
+    ///
+    /// //b = [-1.0, 0.5, 1.0]
+    /// let (b, count) = a.clamp_counted(-1.0, 1.0);
+    ///
+    /// assert_eq!(b.get_data(), &vec!{-1.0, 0.5, 1.0});
+    /// assert_eq!(count, 2);
+    /// ```
+    pub fn clamp_counted(&self, min: f32, max: f32) -> (Tensor<f32>, usize){
+        let mut return_data = Vec::with_capacity(self.get_data().len());
+        let mut count: usize = 0;
+
+        for i in 0..self.get_data().len(){
+            let val = self.get_data()[i];
+            if val < min{
+                return_data.push(min);
+                count += 1;
+            }
+            else if val > max{
+                return_data.push(max);
+                count += 1;
+            }
+            else{
+                return_data.push(val);
+            }
+        }
+
+        (Tensor::from_data(&return_data, self.get_shape()).unwrap(), count)
+    }
+
+    /// Clips elements below lower percentile and above upper percentile
+    /// to values of those percentiles, percentiles are in range 0..=100
+    /// and use linear interpolation between sorted elements
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[-100.0, 1.0, 2.0, 3.0, 100.0], &[5]).unwrap();
+    ///
+    /// //b = [1.0, 1.0, 2.0, 3.0, 3.0]
+    /// let b: Tensor<f32> = a.winsorize(25.0, 75.0);
+    ///
+    /// assert_eq!(b.get_data(), &vec!{1.0, 1.0, 2.0, 3.0, 3.0});
+    /// ```
+    pub fn winsorize(&self, lower_pct: f32, upper_pct: f32) -> Tensor<f32>{
+        if self.get_data().is_empty(){
+            return self.clone();
+        }
+
+        let mut sorted: Vec<f32> = self.get_data().clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let percentile = |pct: f32| -> f32{
+            let rank = pct.clamp(0.0, 100.0) / 100.0 * (sorted.len() - 1) as f32;
+            let low = rank.floor() as usize;
+            let high = rank.ceil() as usize;
+            sorted[low] + (sorted[high] - sorted[low]) * (rank - low as f32)
+        };
+
+        let min = percentile(lower_pct);
+        let max = percentile(upper_pct);
+
+        self.clamp_counted(min, max).0
+    }
+}
@@ -0,0 +1,53 @@
+use crate::tensor::*;
+
+impl Tensor<f32>{
+    /// Panics if tensors have different shapes
+    /// or any element differs by more than epsilon.
+    /// Panic message contains first differing position and both values
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::fill(1.0, &[2, 2]);
+    /// let b: Tensor<f32> = Tensor::fill(1.000001, &[2, 2]);
+    ///
+    /// a.assert_approx_eq(&b, 1e-5);
+    /// ```
+    pub fn assert_approx_eq(&self, tens2: &Tensor<f32>, epsilon: f32){
+        if self.get_shape() != tens2.get_shape(){
+            panic!("Tensors differ in shape: left={:?} right={:?}", self.get_shape(), tens2.get_shape());
+        }
+
+        for i in 0..self.get_data().len(){
+            let a = self.get_data()[i];
+            let b = tens2.get_data()[i];
+
+            //nan difference never counts as close
+            let difference = (a - b).abs();
+            if difference.is_nan() || difference > epsilon{
+                panic!("Tensors differ at index {} {:?}: left={} right={} (epsilon={})", i, self.idx_to_global(i as u32), a, b, epsilon);
+            }
+        }
+    }
+
+    /// Returns positions and values of all elements matching predicate
+    /// in row-major order
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 20.0, 3.0, 40.0], &[2, 2]).unwrap();
+    ///
+    /// let found = a.argwhere(|x| x > 10.0);
+    ///
+    /// assert_eq!(found, vec!{(vec!{0, 1}, 20.0), (vec!{1, 1}, 40.0)});
+    /// ```
+    pub fn argwhere(&self, predicate: impl Fn(f32) -> bool) -> Vec<(Vec<u32>, f32)>{
+        self.indexed_iter()
+            .filter(|(_, val)| predicate(**val))
+            .map(|(pos, val)| (pos, *val))
+            .collect()
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::tensor::*;
+use crate::cpu::math::reductions::reduced_shape;
+
+/// most frequent value, first one on ties
+fn mode_of<T: Eq + Hash + Copy>(values: impl Iterator<Item = T>) -> Option<T>{
+    let mut counts: HashMap<T, usize> = HashMap::new();
+    let mut order: Vec<T> = Vec::new();
+
+    for val in values{
+        let count = counts.entry(val).or_insert(0);
+        if *count == 0{
+            order.push(val);
+        }
+        *count += 1;
+    }
+
+    let mut mode: Option<T> = None;
+    let mut best: usize = 0;
+    for val in order{
+        if counts[&val] > best{
+            best = counts[&val];
+            mode = Some(val);
+        }
+    }
+
+    mode
+}
+
+impl<T> Tensor<T>
+where
+    T: Default + Eq + Hash + Copy,
+{
+    /// Returns most frequent element in tensor,
+    /// first one in case of tie
+    /// None if tensor is empty
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<i32> = Tensor::from_data(&[1, 2, 2, 3, 2, 1], &[6]).unwrap();
+    ///
+    /// assert_eq!(a.mode(), Some(2));
+    /// ```
+    pub fn mode(&self) -> Option<T>{
+        mode_of(self.get_data().iter().copied())
+    }
+
+    /// Returns most frequent element in each slice along axis,
+    /// with the axis removed
+    /// None if axis out of range or empty
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<i32> = Tensor::from_data(&[1, 2, 1, 3, 3, 4], &[2, 3]).unwrap();
+    ///
+    /// //b = [1, 3]
+    /// let b: Tensor<i32> = a.mode_axis(1).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{1, 3});
+    /// ```
+    pub fn mode_axis(&self, axis: u32) -> Option<Tensor<T>>{
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+        if length == 0{
+            return None;
+        }
+
+        let mut return_data: Vec<T> = Vec::with_capacity((outer * inner) as usize);
+
+        for o in 0..outer{
+            for j in 0..inner{
+                let slice = (0..length).map(|i| self.get_data()[(o*length*inner + i*inner + j) as usize]);
+                return_data.push(mode_of(slice)?);
+            }
+        }
+
+        Tensor::from_data(&return_data, &reduced_shape(self.get_shape(), axis))
+    }
+
+    /// Returns vector of distinct elements
+    /// in order of first occurrence
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<i32> = Tensor::from_data(&[3, 1, 3, 2, 1], &[5]).unwrap();
+    ///
+    /// //b = [3, 1, 2]
+    /// let b: Tensor<i32> = a.unique();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{3, 1, 2});
+    /// ```
+    pub fn unique(&self) -> Tensor<T>{
+        self.unique_counts().0
+    }
+
+    /// Returns vector of distinct elements in order of first occurrence,
+    /// and vector with count of each of them
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<i32> = Tensor::from_data(&[3, 1, 3, 2, 1, 3], &[6]).unwrap();
+    ///
+    /// let (values, counts) = a.unique_counts();
+    ///
+    /// assert_eq!(values.get_data(), &vec!{3, 1, 2});
+    /// assert_eq!(counts.get_data(), &vec!{3, 2, 1});
+    /// ```
+    pub fn unique_counts(&self) -> (Tensor<T>, Tensor<u32>){
+        let mut ids: HashMap<T, usize> = HashMap::new();
+        let mut values: Vec<T> = Vec::new();
+        let mut counts: Vec<u32> = Vec::new();
+
+        for val in self.get_data(){
+            match ids.get(val){
+                Some(id) => counts[*id] += 1,
+                None => {
+                    ids.insert(*val, values.len());
+                    values.push(*val);
+                    counts.push(1);
+                }
+            }
+        }
+
+        let length = values.len() as u32;
+        (Tensor::from_data(&values, &[length]).unwrap(), Tensor::from_data(&counts, &[length]).unwrap())
+    }
+}
+
+impl Tensor<u32>{
+    /// Returns vector with count of each value from 0 to max value,
+    /// at least min_length long
+    /// None if tensor contains u32::MAX, as its count does not fit in the shape
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<u32> = Tensor::from_data(&[0, 1, 1, 3], &[4]).unwrap();
+    ///
+    /// //b = [1, 2, 0, 1, 0]
+    /// let b: Tensor<u32> = a.bincount(5).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{1, 2, 0, 1, 0});
+    /// ```
+    pub fn bincount(&self, min_length: u32) -> Option<Tensor<u32>>{
+        let length = match self.get_data().iter().max(){
+            Some(max) => max.checked_add(1)?.max(min_length),
+            None => min_length,
+        };
+
+        let mut return_data: Vec<u32> = vec![0; length as usize];
+
+        for val in self.get_data(){
+            return_data[*val as usize] += 1;
+        }
+
+        Tensor::from_data(&return_data, &[length])
+    }
+}
@@ -97,4 +97,71 @@ impl Tensor<f32>{
 
         self.set_data(&return_data);
     }
+
+    /// Applies function on elements of three tensors with the same shape
+    /// None if shapes differ
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0], &[3]).unwrap();
+    /// let b: Tensor<f32> = Tensor::from_data(&[2.0, 2.0, 2.0], &[3]).unwrap();
+    /// let c: Tensor<f32> = Tensor::from_data(&[0.0, 1.0, 0.0], &[3]).unwrap();
+    ///
+    /// //d =
+    /// //[2.0, 2.0, 6.0]
+    /// let d: Tensor<f32> = a.zip3_map(&b, &c, |x, y, z| if z > 0.0 {x} else {x * y}).unwrap();
+    ///
+    /// assert_eq!(d.get_data(), &vec!{2.0, 2.0, 6.0})
+    /// ```
+    pub fn zip3_map<F: Fn(f32, f32, f32) -> f32>(&self, tens2: &Tensor<f32>, tens3: &Tensor<f32>, f: F) -> Option<Tensor<f32>>{
+        if self.get_shape() != tens2.get_shape() || self.get_shape() != tens3.get_shape(){
+            return None;
+        }
+
+        let mut return_data = Vec::with_capacity(self.get_data().len());
+
+        for i in 0..self.get_data().len(){
+            return_data.push(f(self.get_data()[i], tens2.get_data()[i], tens3.get_data()[i]));
+        }
+
+        Tensor::from_data(&return_data, self.get_shape())
+    }
+
+    /// Each NaN, +Inf and -Inf element replaced with given values
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[f32::NAN, 1.0, f32::INFINITY], &[3]).unwrap();
+    ///
+    /// //b =
+    /// //[0.0, 1.0, 100.0]
+    /// let b: Tensor<f32> = a.nan_to_num(0.0, 100.0, -100.0);
+    ///
+    /// assert_eq!(b.get_data(), &vec!{0.0, 1.0, 100.0})
+    /// ```
+    pub fn nan_to_num(&self, nan: f32, posinf: f32, neginf: f32) -> Tensor<f32>{
+        let mut return_data = Vec::with_capacity(self.get_data().len());
+
+        for i in 0..self.get_data().len(){
+            let val = self.get_data()[i];
+            if val.is_nan(){
+                return_data.push(nan);
+            }
+            else if val == f32::INFINITY{
+                return_data.push(posinf);
+            }
+            else if val == f32::NEG_INFINITY{
+                return_data.push(neginf);
+            }
+            else{
+                return_data.push(val);
+            }
+        }
+
+        Tensor::from_data(&return_data, self.get_shape()).unwrap()
+    }
 }
@@ -3,3 +3,13 @@ pub mod division;
 pub mod multiplication;
 pub mod subtraction;
 pub mod functions;
+pub mod comparison;
+pub mod norms;
+pub mod reductions;
+pub mod calculus;
+pub mod rolling;
+pub mod clamping;
+pub mod counting;
+pub mod probability;
+pub mod operators;
+pub mod special;
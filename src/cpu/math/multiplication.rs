@@ -140,3 +140,28 @@ where
         return_data
     }
 }
+
+impl Tensor<i64>{
+    /// returns the product of each element in tensor
+    /// or None if it overflows
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<i64> = Tensor::fill(2, &[3]);
+    /// let b: Tensor<i64> = Tensor::fill(i64::MAX, &[2]);
+    ///
+    /// assert_eq!(a.checked_product_all(), Some(8));
+    /// assert_eq!(b.checked_product_all(), None);
+    /// ```
+    pub fn checked_product_all(&self) -> Option<i64>{
+        let mut return_data: i64 = 1;
+
+        for i in 0..self.get_data().len(){
+            return_data = return_data.checked_mul(self.get_data()[i])?;
+        }
+
+        Some(return_data)
+    }
+}
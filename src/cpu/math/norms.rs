@@ -0,0 +1,115 @@
+use crate::tensor::*;
+
+impl Tensor<f32>{
+    /// Each slice along axis, with p-norm bigger than max_norm
+    /// is scaled down to have norm equal max_norm
+    /// None if axis out of range
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[3.0, 4.0, 0.3, 0.4], &[2, 2]).unwrap();
+    ///
+    /// //b =
+    /// //[0.6, 0.8]
+    /// //[0.3, 0.4]
+    /// let b: Tensor<f32> = a.renorm(2.0, 0, 1.0).unwrap();
+    ///
+    /// assert!((b.get_data()[0] - 0.6).abs() < 1e-6);
+    /// assert!((b.get_data()[1] - 0.8).abs() < 1e-6);
+    /// assert_eq!(b.get_data()[2..], [0.3, 0.4]);
+    /// ```
+    pub fn renorm(&self, p: f32, axis: u32, max_norm: f32) -> Option<Tensor<f32>>{
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+
+        let mut return_data = self.get_data().clone();
+
+        for i in 0..length{
+            let mut norm: f32 = 0.0;
+            for o in 0..outer{
+                for j in 0..inner{
+                    norm += self.get_data()[(o*length*inner + i*inner + j) as usize].abs().powf(p);
+                }
+            }
+            norm = norm.powf(1.0/p);
+
+            if norm <= max_norm{
+                continue;
+            }
+
+            let scale = max_norm / norm;
+            for o in 0..outer{
+                for j in 0..inner{
+                    return_data[(o*length*inner + i*inner + j) as usize] *= scale;
+                }
+            }
+        }
+
+        Tensor::from_data(&return_data, self.get_shape())
+    }
+
+    /// Scales whole tensor down, so its L2 norm is at most max_norm
+    /// tensor with smaller norm is unchanged
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[6.0, 8.0], &[2]).unwrap();
+    ///
+    /// //b = [3.0, 4.0]
+    /// let b: Tensor<f32> = a.clip_norm(5.0);
+    ///
+    /// assert_eq!(b.get_data(), &vec!{3.0, 4.0});
+    /// ```
+    pub fn clip_norm(&self, max_norm: f32) -> Tensor<f32>{
+        let norm: f32 = self.get_data().iter()
+            .map(|val| val * val)
+            .sum::<f32>()
+            .sqrt();
+
+        if norm <= max_norm{
+            return self.clone();
+        }
+
+        self.mul(max_norm / norm)
+    }
+
+    /// Divides each row (slice along the last axis) by its L2 norm
+    /// rows with zero norm are unchanged
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[3.0, 4.0, 0.0, 0.0], &[2, 2]).unwrap();
+    ///
+    /// //b =
+    /// //[0.6, 0.8]
+    /// //[0.0, 0.0]
+    /// let b: Tensor<f32> = a.normalize_rows();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{0.6, 0.8, 0.0, 0.0});
+    /// ```
+    pub fn normalize_rows(&self) -> Tensor<f32>{
+        let row_size = match self.get_shape().last(){
+            Some(size) if *size > 0 => *size as usize,
+            _ => return self.clone(),
+        };
+
+        let mut return_data = self.get_data().clone();
+
+        for row in return_data.chunks_mut(row_size){
+            let norm: f32 = row.iter().map(|val| val * val).sum::<f32>().sqrt();
+            if norm == 0.0{
+                continue;
+            }
+            for val in row.iter_mut(){
+                *val /= norm;
+            }
+        }
+
+        Tensor::from_data(&return_data, self.get_shape()).unwrap()
+    }
+}
@@ -0,0 +1,181 @@
+use std::ops::{Add, Sub, Mul, Div};
+use crate::tensor::*;
+
+impl Tensor<f32>{
+    fn elementwise(&self, tens2: &Tensor<f32>, f: fn(f32, f32) -> f32) -> Option<Tensor<f32>>{
+        if self.get_shape() != tens2.get_shape(){
+            return None;
+        }
+
+        let mut return_data: Vec<f32> = Vec::with_capacity(self.get_data().len());
+        for i in 0..self.get_data().len(){
+            return_data.push(f(self.get_data()[i], tens2.get_data()[i]));
+        }
+
+        Tensor::from_data(&return_data, self.get_shape())
+    }
+
+    fn elementwise_or_panic(&self, tens2: &Tensor<f32>, op: &str, f: fn(f32, f32) -> f32) -> Tensor<f32>{
+        match self.elementwise(tens2, f){
+            Some(tensor) => tensor,
+            None => panic!("Cannot {} tensors with different shapes: left={:?} right={:?}", op, self.get_shape(), tens2.get_shape()),
+        }
+    }
+
+    /// Element-wise addition of two tensors
+    /// None if different shapes
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::fill(1.0, &[2, 2]);
+    /// let b: Tensor<f32> = Tensor::fill(2.0, &[2, 2]);
+    ///
+    /// let c: Tensor<f32> = a.try_add(&b).unwrap();
+    ///
+    /// assert_eq!(c.get_data(), &vec!{3.0, 3.0, 3.0, 3.0});
+    /// assert!(a.try_add(&Tensor::fill(2.0, &[4])).is_none());
+    /// ```
+    pub fn try_add(&self, tens2: &Tensor<f32>) -> Option<Tensor<f32>>{
+        self.elementwise(tens2, |a, b| a + b)
+    }
+
+    /// Element-wise subtraction of two tensors
+    /// None if different shapes
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::fill(1.0, &[2, 2]);
+    /// let b: Tensor<f32> = Tensor::fill(2.0, &[2, 2]);
+    ///
+    /// let c: Tensor<f32> = a.try_sub(&b).unwrap();
+    ///
+    /// assert_eq!(c.get_data(), &vec!{-1.0, -1.0, -1.0, -1.0});
+    /// ```
+    pub fn try_sub(&self, tens2: &Tensor<f32>) -> Option<Tensor<f32>>{
+        self.elementwise(tens2, |a, b| a - b)
+    }
+
+    /// Element-wise multiplication of two tensors
+    /// None if different shapes
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::fill(3.0, &[2, 2]);
+    /// let b: Tensor<f32> = Tensor::fill(2.0, &[2, 2]);
+    ///
+    /// let c: Tensor<f32> = a.try_mul(&b).unwrap();
+    ///
+    /// assert_eq!(c.get_data(), &vec!{6.0, 6.0, 6.0, 6.0});
+    /// ```
+    pub fn try_mul(&self, tens2: &Tensor<f32>) -> Option<Tensor<f32>>{
+        self.elementwise(tens2, |a, b| a * b)
+    }
+
+    /// Element-wise division of two tensors
+    /// None if different shapes
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::fill(3.0, &[2, 2]);
+    /// let b: Tensor<f32> = Tensor::fill(2.0, &[2, 2]);
+    ///
+    /// let c: Tensor<f32> = a.try_div(&b).unwrap();
+    ///
+    /// assert_eq!(c.get_data(), &vec!{1.5, 1.5, 1.5, 1.5});
+    /// ```
+    pub fn try_div(&self, tens2: &Tensor<f32>) -> Option<Tensor<f32>>{
+        self.elementwise(tens2, |a, b| a / b)
+    }
+}
+
+/// Element-wise addition with `&a + &b`
+///
+/// Panics if shapes are different, use `try_add` to get None instead
+///
+/// # Example
+/// ```
+/// use flashlight_tensor::prelude::*;
+///
+/// let a: Tensor<f32> = Tensor::fill(1.0, &[2]);
+/// let b: Tensor<f32> = Tensor::fill(2.0, &[2]);
+///
+/// assert_eq!((&a + &b).get_data(), &vec!{3.0, 3.0});
+/// ```
+impl Add for &Tensor<f32>{
+    type Output = Tensor<f32>;
+
+    fn add(self, tens2: &Tensor<f32>) -> Tensor<f32>{
+        self.elementwise_or_panic(tens2, "add", |a, b| a + b)
+    }
+}
+
+/// Element-wise subtraction with `&a - &b`
+///
+/// Panics if shapes are different, use `try_sub` to get None instead
+///
+/// # Example
+/// ```
+/// use flashlight_tensor::prelude::*;
+///
+/// let a: Tensor<f32> = Tensor::fill(1.0, &[2]);
+/// let b: Tensor<f32> = Tensor::fill(2.0, &[2]);
+///
+/// assert_eq!((&a - &b).get_data(), &vec!{-1.0, -1.0});
+/// ```
+impl Sub for &Tensor<f32>{
+    type Output = Tensor<f32>;
+
+    fn sub(self, tens2: &Tensor<f32>) -> Tensor<f32>{
+        self.elementwise_or_panic(tens2, "subtract", |a, b| a - b)
+    }
+}
+
+/// Element-wise multiplication with `&a * &b`
+///
+/// Panics if shapes are different, use `try_mul` to get None instead
+///
+/// # Example
+/// ```
+/// use flashlight_tensor::prelude::*;
+///
+/// let a: Tensor<f32> = Tensor::fill(3.0, &[2]);
+/// let b: Tensor<f32> = Tensor::fill(2.0, &[2]);
+///
+/// assert_eq!((&a * &b).get_data(), &vec!{6.0, 6.0});
+/// ```
+impl Mul for &Tensor<f32>{
+    type Output = Tensor<f32>;
+
+    fn mul(self, tens2: &Tensor<f32>) -> Tensor<f32>{
+        self.elementwise_or_panic(tens2, "multiply", |a, b| a * b)
+    }
+}
+
+/// Element-wise division with `&a / &b`
+///
+/// Panics if shapes are different, use `try_div` to get None instead
+///
+/// # Example
+/// ```
+/// use flashlight_tensor::prelude::*;
+///
+/// let a: Tensor<f32> = Tensor::fill(3.0, &[2]);
+/// let b: Tensor<f32> = Tensor::fill(2.0, &[2]);
+///
+/// assert_eq!((&a / &b).get_data(), &vec!{1.5, 1.5});
+/// ```
+impl Div for &Tensor<f32>{
+    type Output = Tensor<f32>;
+
+    fn div(self, tens2: &Tensor<f32>) -> Tensor<f32>{
+        self.elementwise_or_panic(tens2, "divide", |a, b| a / b)
+    }
+}
@@ -0,0 +1,250 @@
+use crate::tensor::*;
+use crate::cpu::math::reductions::reduced_shape;
+
+/// guards against ln(0) and division by 0
+const PROBABILITY_EPSILON: f32 = 1e-12;
+
+impl Tensor<f32>{
+    /// Projects each slice along axis onto probability simplex,
+    /// closest point in euclidean distance with non negative values summing to 1
+    /// None if axis out of range or empty
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[0.5, 1.5, -1.0], &[3]).unwrap();
+    ///
+    /// //b = [0.0, 1.0, 0.0]
+    /// let b: Tensor<f32> = a.project_simplex(0).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{0.0, 1.0, 0.0});
+    /// ```
+    pub fn project_simplex(&self, axis: u32) -> Option<Tensor<f32>>{
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+        if length == 0{
+            return None;
+        }
+
+        let mut return_data: Vec<f32> = vec![0.0; self.get_data().len()];
+
+        for o in 0..outer{
+            for j in 0..inner{
+                let id = |i: u32| (o*length*inner + i*inner + j) as usize;
+
+                let mut sorted: Vec<f32> = (0..length).map(|i| self.get_data()[id(i)]).collect();
+                sorted.sort_by(|a, b| b.total_cmp(a));
+
+                let mut cumulative: f32 = 0.0;
+                let mut theta: f32 = 0.0;
+                for (k, value) in sorted.iter().enumerate(){
+                    cumulative += value;
+                    let candidate = (cumulative - 1.0) / (k + 1) as f32;
+                    if value - candidate > 0.0{
+                        theta = candidate;
+                    }
+                }
+
+                for i in 0..length{
+                    return_data[id(i)] = (self.get_data()[id(i)] - theta).max(0.0);
+                }
+            }
+        }
+
+        Tensor::from_data(&return_data, self.get_shape())
+    }
+
+    /// Returns shannon entropy -sum(p * ln(p)) of each slice along axis,
+    /// with the axis removed, where 0 * ln(0) = 0
+    /// None if axis out of range
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[0.5, 0.5, 1.0, 0.0], &[2, 2]).unwrap();
+    ///
+    /// //b = [ln(2), 0.0]
+    /// let b: Tensor<f32> = a.entropy_axis(1).unwrap();
+    ///
+    /// assert!((b.get_data()[0] - 2f32.ln()).abs() < 1e-6);
+    /// assert_eq!(b.get_data()[1], 0.0);
+    /// ```
+    pub fn entropy_axis(&self, axis: u32) -> Option<Tensor<f32>>{
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+
+        let mut return_data: Vec<f32> = Vec::with_capacity((outer * inner) as usize);
+
+        for o in 0..outer{
+            for j in 0..inner{
+                let mut entropy: f32 = 0.0;
+                for i in 0..length{
+                    let p = self.get_data()[(o*length*inner + i*inner + j) as usize];
+                    if p > 0.0{
+                        entropy -= p * p.ln();
+                    }
+                }
+                return_data.push(entropy);
+            }
+        }
+
+        Tensor::from_data(&return_data, &reduced_shape(self.get_shape(), axis))
+    }
+
+    /// Returns kullback-leibler divergence sum(p * ln(p/q)) of each slice along axis,
+    /// with the axis removed. p is self and q is second tensor
+    /// None if axis out of range or shapes differ
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let p: Tensor<f32> = Tensor::from_data(&[0.5, 0.5], &[2]).unwrap();
+    /// let q: Tensor<f32> = Tensor::from_data(&[0.25, 0.75], &[2]).unwrap();
+    ///
+    /// let kl: Tensor<f32> = p.kl_div(&q, 0).unwrap();
+    ///
+    /// let expected = 0.5 * (0.5f32 / 0.25).ln() + 0.5 * (0.5f32 / 0.75).ln();
+    /// assert!((kl.get_data()[0] - expected).abs() < 1e-6);
+    /// ```
+    pub fn kl_div(&self, tens2: &Tensor<f32>, axis: u32) -> Option<Tensor<f32>>{
+        if self.get_shape() != tens2.get_shape(){
+            return None;
+        }
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+
+        let mut return_data: Vec<f32> = Vec::with_capacity((outer * inner) as usize);
+
+        for o in 0..outer{
+            for j in 0..inner{
+                let mut divergence: f32 = 0.0;
+                for i in 0..length{
+                    let id = (o*length*inner + i*inner + j) as usize;
+                    let p = self.get_data()[id];
+                    let q = tens2.get_data()[id];
+                    if p > 0.0{
+                        divergence += p * (p / q.max(PROBABILITY_EPSILON)).ln();
+                    }
+                }
+                return_data.push(divergence);
+            }
+        }
+
+        Tensor::from_data(&return_data, &reduced_shape(self.get_shape(), axis))
+    }
+
+    /// Returns jensen-shannon divergence 0.5 * KL(p||m) + 0.5 * KL(q||m),
+    /// where m = (p + q) / 2, of each slice along axis, with the axis removed
+    /// None if axis out of range or shapes differ
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let p: Tensor<f32> = Tensor::from_data(&[1.0, 0.0], &[2]).unwrap();
+    /// let q: Tensor<f32> = Tensor::from_data(&[0.0, 1.0], &[2]).unwrap();
+    ///
+    /// //disjoint distributions have the biggest divergence ln(2)
+    /// let js: Tensor<f32> = p.jensen_shannon(&q, 0).unwrap();
+    ///
+    /// assert!((js.get_data()[0] - 2f32.ln()).abs() < 1e-6);
+    /// ```
+    pub fn jensen_shannon(&self, tens2: &Tensor<f32>, axis: u32) -> Option<Tensor<f32>>{
+        let mixture = self.tens_add(tens2)?.mul(0.5);
+
+        let self_divergence = self.kl_div(&mixture, axis)?;
+        let tens2_divergence = tens2.kl_div(&mixture, axis)?;
+
+        Some(self_divergence.tens_add(&tens2_divergence)?.mul(0.5))
+    }
+
+    /// Cumulative distribution along axis of probabilities,
+    /// each slice is divided by its sum, so it rises from first probability to 1.0
+    /// compare with uniform sample to draw categorical outcome
+    /// None if axis is out of range or any slice sum is not positive
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 1.0, 1.0, 1.0], &[4]).unwrap();
+    ///
+    /// //b = [0.25, 0.5, 0.75, 1.0]
+    /// let b: Tensor<f32> = a.cumulative_distribution_axis(0).unwrap();
+    ///
+    /// b.assert_approx_eq(&Tensor::from_data(&[0.25, 0.5, 0.75, 1.0], &[4]).unwrap(), 1e-6);
+    /// ```
+    pub fn cumulative_distribution_axis(&self, axis: u32) -> Option<Tensor<f32>>{
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+
+        let mut return_data: Vec<f32> = vec![0.0; self.get_data().len()];
+
+        for o in 0..outer{
+            for j in 0..inner{
+                let mut total: f32 = 0.0;
+                for i in 0..length{
+                    let id = (o*length*inner + i*inner + j) as usize;
+                    total += self.get_data()[id];
+                }
+                if total.is_nan() || total <= 0.0{
+                    return None;
+                }
+
+                let mut sum: f32 = 0.0;
+                for i in 0..length{
+                    let id = (o*length*inner + i*inner + j) as usize;
+                    sum += self.get_data()[id];
+                    return_data[id] = sum / total;
+                }
+            }
+        }
+
+        Tensor::from_data(&return_data, self.get_shape())
+    }
+
+    /// Mask of smallest set of most probable elements along axis
+    /// with cumulative probability reaching p (nucleus filter)
+    /// most probable element is always kept, ties keep earlier element first
+    /// None if axis is out of range or p is not in (0, 1]
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[0.1, 0.5, 0.3, 0.1], &[4]).unwrap();
+    ///
+    /// let mask: Tensor<bool> = a.top_p_mask(0, 0.75).unwrap();
+    ///
+    /// assert_eq!(mask.get_data(), &vec!{false, true, true, false});
+    /// ```
+    pub fn top_p_mask(&self, axis: u32, p: f32) -> Option<Tensor<bool>>{
+        if !(p > 0.0 && p <= 1.0){
+            return None;
+        }
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+
+        let mut return_data: Vec<bool> = vec![false; self.get_data().len()];
+        let mut order: Vec<u32> = Vec::with_capacity(length as usize);
+
+        for o in 0..outer{
+            for j in 0..inner{
+                let id = |i: u32| (o*length*inner + i*inner + j) as usize;
+
+                order.clear();
+                order.extend(0..length);
+                order.sort_by(|a, b| self.get_data()[id(*b)].total_cmp(&self.get_data()[id(*a)]));
+
+                let mut cumulative: f32 = 0.0;
+                for i in 0..order.len(){
+                    return_data[id(order[i])] = true;
+                    cumulative += self.get_data()[id(order[i])];
+                    if cumulative >= p{
+                        break;
+                    }
+                }
+            }
+        }
+
+        Tensor::from_data(&return_data, self.get_shape())
+    }
+}
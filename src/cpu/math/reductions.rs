@@ -0,0 +1,500 @@
+use crate::tensor::*;
+use crate::cpu::broadcasting::helpers::{get_aligned_broadcast_shape, broadcast_position};
+
+/// shape with axis removed,
+/// [1] when no dimensions are left
+pub(crate) fn reduced_shape(shape: &[u32], axis: u32) -> Vec<u32>{
+    let mut output_shape: Vec<u32> = shape.to_vec();
+    output_shape.remove(axis as usize);
+
+    if output_shape.is_empty(){
+        output_shape.push(1);
+    }
+
+    output_shape
+}
+
+impl Tensor<f32>{
+    /// Returns log(sum(exp(x))) along axis, with the axis removed
+    /// computed in stable form max + log(sum(exp(x - max)))
+    /// None if axis out of range
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1000.0, 1000.0, 0.0, 0.0], &[2, 2]).unwrap();
+    ///
+    /// //b = [1000.0 + ln(2), ln(2)]
+    /// let b: Tensor<f32> = a.logsumexp_axis(1).unwrap();
+    ///
+    /// assert_eq!(b.get_shape(), &vec!{2});
+    /// assert!((b.get_data()[0] - (1000.0 + 2f32.ln())).abs() < 1e-3);
+    /// assert!((b.get_data()[1] - 2f32.ln()).abs() < 1e-6);
+    /// ```
+    pub fn logsumexp_axis(&self, axis: u32) -> Option<Tensor<f32>>{
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+
+        let mut return_data: Vec<f32> = Vec::with_capacity((outer * inner) as usize);
+
+        for o in 0..outer{
+            for j in 0..inner{
+                let mut max = f32::NEG_INFINITY;
+                for i in 0..length{
+                    max = max.max(self.get_data()[(o*length*inner + i*inner + j) as usize]);
+                }
+
+                if max.is_infinite(){
+                    return_data.push(max);
+                    continue;
+                }
+
+                let mut sum: f32 = 0.0;
+                for i in 0..length{
+                    sum += (self.get_data()[(o*length*inner + i*inner + j) as usize] - max).exp();
+                }
+
+                return_data.push(max + sum.ln());
+            }
+        }
+
+        Tensor::from_data(&return_data, &reduced_shape(self.get_shape(), axis))
+    }
+
+    /// Returns sum over diagonal of two axes with equal length
+    /// with both axes removed. For matrix it is a trace
+    /// None if axes are the same, out of range or have different length
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0], &[2, 2, 2]).unwrap();
+    ///
+    /// //b = [1.0 + 7.0, 2.0 + 8.0]
+    /// let b: Tensor<f32> = a.trace_axes(0, 1).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{8.0, 10.0});
+    /// assert_eq!(b.get_shape(), &vec!{2});
+    /// ```
+    pub fn trace_axes(&self, axis1: u32, axis2: u32) -> Option<Tensor<f32>>{
+        let rank = self.get_shape().len() as u32;
+        if axis1 == axis2 || axis1 >= rank || axis2 >= rank{
+            return None;
+        }
+        if self.get_shape()[axis1 as usize] != self.get_shape()[axis2 as usize]{
+            return None;
+        }
+
+        let diagonal = self.get_shape()[axis1 as usize];
+        let output_shape: Vec<u32> = self.get_shape().iter().enumerate()
+            .filter(|(i, _)| *i as u32 != axis1 && *i as u32 != axis2)
+            .map(|(_, size)| *size)
+            .collect();
+
+        let output_capacity: u32 = output_shape.iter().product();
+        let mut return_data: Vec<f32> = Vec::with_capacity(output_capacity as usize);
+
+        for i in 0..output_capacity{
+            let output_position = idx_to_global(i, &output_shape);
+
+            let mut sum: f32 = 0.0;
+            for d in 0..diagonal{
+                let mut position: Vec<u32> = Vec::with_capacity(rank as usize);
+                let mut output_id = 0;
+                for axis in 0..rank{
+                    if axis == axis1 || axis == axis2{
+                        position.push(d);
+                    }
+                    else{
+                        position.push(output_position[output_id]);
+                        output_id += 1;
+                    }
+                }
+                sum += self.value(&position).unwrap();
+            }
+            return_data.push(sum);
+        }
+
+        if output_shape.is_empty(){
+            return Tensor::from_data(&return_data, &[1]);
+        }
+        Tensor::from_data(&return_data, &output_shape)
+    }
+}
+
+impl<T> Tensor<T>
+where
+    T: Default + std::ops::Mul<Output = T> + Copy,
+{
+    /// Returns product of elements along axis, with the axis removed
+    /// None if axis out of range or empty
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]).unwrap();
+    ///
+    /// //b = [6.0, 120.0]
+    /// let b: Tensor<f32> = a.prod_axis(1).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{6.0, 120.0});
+    /// assert_eq!(b.get_shape(), &vec!{2});
+    /// ```
+    pub fn prod_axis(&self, axis: u32) -> Option<Tensor<T>>{
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+        if length == 0{
+            return None;
+        }
+
+        let mut return_data: Vec<T> = Vec::with_capacity((outer * inner) as usize);
+
+        for o in 0..outer{
+            for j in 0..inner{
+                let mut value: T = self.get_data()[(o*length*inner + j) as usize];
+                for i in 1..length{
+                    value = value * self.get_data()[(o*length*inner + i*inner + j) as usize];
+                }
+                return_data.push(value);
+            }
+        }
+
+        Tensor::from_data(&return_data, &reduced_shape(self.get_shape(), axis))
+    }
+}
+
+impl<T> Tensor<T>
+where
+    T: Default + std::ops::Add<Output = T> + Copy,
+{
+    /// Returns sum of elements along axis, with the axis removed
+    /// None if axis out of range
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::fill(1.0, &[2, 3, 4]);
+    ///
+    /// let b: Tensor<f32> = a.sum_axis(1).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{3.0; 8});
+    /// assert_eq!(b.get_shape(), &vec!{2, 4});
+    /// ```
+    pub fn sum_axis(&self, axis: u32) -> Option<Tensor<T>>{
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+
+        let mut return_data: Vec<T> = Vec::with_capacity((outer * inner) as usize);
+
+        for o in 0..outer{
+            for j in 0..inner{
+                let mut value: T = T::default();
+                for i in 0..length{
+                    value = value + self.get_data()[(o*length*inner + i*inner + j) as usize];
+                }
+                return_data.push(value);
+            }
+        }
+
+        Tensor::from_data(&return_data, &reduced_shape(self.get_shape(), axis))
+    }
+}
+
+impl Tensor<f32>{
+    /// Returns mean of elements along axis, with the axis removed
+    /// None if axis out of range or empty
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]).unwrap();
+    ///
+    /// //b = [2.5, 3.5, 4.5]
+    /// let b: Tensor<f32> = a.mean_axis(0).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{2.5, 3.5, 4.5});
+    /// assert_eq!(b.get_shape(), &vec!{3});
+    /// ```
+    pub fn mean_axis(&self, axis: u32) -> Option<Tensor<f32>>{
+        let (_, length, _) = split_axis(self.get_shape(), axis)?;
+        if length == 0{
+            return None;
+        }
+
+        let sum = self.sum_axis(axis)?;
+
+        Some(sum.map(|val| val / length as f32))
+    }
+
+    /// Returns mean of all elements
+    /// NaN if tensor is empty
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]).unwrap();
+    ///
+    /// assert_eq!(a.mean(), 3.5);
+    /// ```
+    pub fn mean(&self) -> f32{
+        self.sum() / self.get_data().len() as f32
+    }
+
+    /// Returns mean along axis of elements where mask is true, with the axis removed
+    /// slices without any true element give 0.0
+    /// None if mask has different shape or axis out of range
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 9.0, 4.0, 5.0, 6.0], &[2, 3]).unwrap();
+    /// let mask: Tensor<bool> = Tensor::from_data(&[true, true, false, false, false, false], &[2, 3]).unwrap();
+    ///
+    /// //b = [1.5, 0.0]
+    /// let b: Tensor<f32> = a.masked_mean(&mask, 1).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{1.5, 0.0});
+    /// ```
+    pub fn masked_mean(&self, mask: &Tensor<bool>, axis: u32) -> Option<Tensor<f32>>{
+        if self.get_shape() != mask.get_shape(){
+            return None;
+        }
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+
+        let mut return_data: Vec<f32> = Vec::with_capacity((outer * inner) as usize);
+
+        for o in 0..outer{
+            for j in 0..inner{
+                let mut sum: f32 = 0.0;
+                let mut count: u32 = 0;
+                for i in 0..length{
+                    let id = (o*length*inner + i*inner + j) as usize;
+                    if mask.get_data()[id]{
+                        sum += self.get_data()[id];
+                        count += 1;
+                    }
+                }
+
+                if count == 0{
+                    return_data.push(0.0);
+                }
+                else{
+                    return_data.push(sum / count as f32);
+                }
+            }
+        }
+
+        Tensor::from_data(&return_data, &reduced_shape(self.get_shape(), axis))
+    }
+
+    /// Returns sum(x * w) / sum(w) along axis, with the axis removed
+    /// weights are broadcast from the right against tensor, like [length] onto [batch, length]
+    /// slices with zero total weight give 0.0
+    /// None if weights can not be broadcast to tensor shape or axis out of range
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]).unwrap();
+    /// let w: Tensor<f32> = Tensor::from_data(&[1.0, 0.0, 1.0], &[3]).unwrap();
+    ///
+    /// //b = [2.0, 5.0]
+    /// let b: Tensor<f32> = a.weighted_mean(&w, 1).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{2.0, 5.0});
+    /// ```
+    pub fn weighted_mean(&self, weights: &Tensor<f32>, axis: u32) -> Option<Tensor<f32>>{
+        let broadcast_shape = get_aligned_broadcast_shape(self.get_shape(), weights.get_shape())?;
+        if &broadcast_shape != self.get_shape(){
+            return None;
+        }
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+
+        let mut return_data: Vec<f32> = Vec::with_capacity((outer * inner) as usize);
+
+        for o in 0..outer{
+            for j in 0..inner{
+                let mut weighted_sum: f32 = 0.0;
+                let mut weight_sum: f32 = 0.0;
+                for i in 0..length{
+                    let id = o*length*inner + i*inner + j;
+                    let weight_position = broadcast_position(&idx_to_global(id, self.get_shape()), weights.get_shape());
+                    let weight = *weights.value(&weight_position)?;
+
+                    weighted_sum += self.get_data()[id as usize] * weight;
+                    weight_sum += weight;
+                }
+
+                if weight_sum == 0.0{
+                    return_data.push(0.0);
+                }
+                else{
+                    return_data.push(weighted_sum / weight_sum);
+                }
+            }
+        }
+
+        Tensor::from_data(&return_data, &reduced_shape(self.get_shape(), axis))
+    }
+}
+
+impl<T> Tensor<T>
+where
+    T: Default + PartialOrd + Copy,
+{
+    /// Returns biggest element of tensor
+    /// None if tensor is empty
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 7.0, 3.0, -4.0, 5.0, 6.0], &[2, 3]).unwrap();
+    ///
+    /// assert_eq!(a.max(), Some(7.0));
+    /// ```
+    pub fn max(&self) -> Option<T>{
+        let mut max: T = *self.get_data().first()?;
+        for i in 1..self.get_data().len(){
+            if self.get_data()[i] > max{
+                max = self.get_data()[i];
+            }
+        }
+
+        Some(max)
+    }
+
+    /// Returns smallest element of tensor
+    /// None if tensor is empty
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 7.0, 3.0, -4.0, 5.0, 6.0], &[2, 3]).unwrap();
+    ///
+    /// assert_eq!(a.min(), Some(-4.0));
+    /// ```
+    pub fn min(&self) -> Option<T>{
+        let mut min: T = *self.get_data().first()?;
+        for i in 1..self.get_data().len(){
+            if self.get_data()[i] < min{
+                min = self.get_data()[i];
+            }
+        }
+
+        Some(min)
+    }
+
+    /// Returns flat index of biggest element, first one on ties
+    /// None if tensor is empty
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 7.0, 3.0, -4.0, 7.0, 6.0], &[2, 3]).unwrap();
+    ///
+    /// assert_eq!(a.argmax(), Some(1));
+    /// ```
+    pub fn argmax(&self) -> Option<u32>{
+        if self.get_data().is_empty(){
+            return None;
+        }
+
+        let mut id: usize = 0;
+        for i in 1..self.get_data().len(){
+            if self.get_data()[i] > self.get_data()[id]{
+                id = i;
+            }
+        }
+
+        Some(id as u32)
+    }
+
+    /// Returns flat index of smallest element, first one on ties
+    /// None if tensor is empty
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 7.0, 3.0, -4.0, 5.0, -4.0], &[2, 3]).unwrap();
+    ///
+    /// assert_eq!(a.argmin(), Some(3));
+    /// ```
+    pub fn argmin(&self) -> Option<u32>{
+        if self.get_data().is_empty(){
+            return None;
+        }
+
+        let mut id: usize = 0;
+        for i in 1..self.get_data().len(){
+            if self.get_data()[i] < self.get_data()[id]{
+                id = i;
+            }
+        }
+
+        Some(id as u32)
+    }
+
+    /// Returns position of biggest element, first one on ties
+    /// None if tensor is empty
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 7.0, 3.0, -4.0, 5.0, 9.0], &[2, 3]).unwrap();
+    ///
+    /// assert_eq!(a.argmax_coords(), Some(vec!{1, 2}));
+    /// ```
+    pub fn argmax_coords(&self) -> Option<Vec<u32>>{
+        let id = self.argmax()?;
+
+        Some(idx_to_global(id, self.get_shape()))
+    }
+
+    /// Returns index of biggest element along axis, with the axis removed,
+    /// first one on ties
+    /// None if axis out of range or empty
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 7.0, 3.0, -4.0, 5.0, 9.0], &[2, 3]).unwrap();
+    ///
+    /// //b = [1, 2]
+    /// let b: Tensor<u32> = a.argmax_axis(1).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{1, 2});
+    /// ```
+    pub fn argmax_axis(&self, axis: u32) -> Option<Tensor<u32>>{
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+        if length == 0{
+            return None;
+        }
+
+        let mut return_data: Vec<u32> = Vec::with_capacity((outer * inner) as usize);
+
+        for o in 0..outer{
+            for j in 0..inner{
+                let mut best: u32 = 0;
+                for i in 1..length{
+                    if self.get_data()[(o*length*inner + i*inner + j) as usize] > self.get_data()[(o*length*inner + best*inner + j) as usize]{
+                        best = i;
+                    }
+                }
+                return_data.push(best);
+            }
+        }
+
+        Tensor::from_data(&return_data, &reduced_shape(self.get_shape(), axis))
+    }
+}
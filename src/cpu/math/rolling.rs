@@ -0,0 +1,168 @@
+use crate::tensor::*;
+
+impl Tensor<f32>{
+    /// Returns max of each element and window-1 elements before it along axis,
+    /// with the same shape. Elements at the beginning use the shorter window
+    /// None if axis out of range or window is 0
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 3.0, 2.0, 0.0, 1.0], &[5]).unwrap();
+    ///
+    /// //b = [1.0, 3.0, 3.0, 2.0, 1.0]
+    /// let b: Tensor<f32> = a.causal_max(0, 2).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{1.0, 3.0, 3.0, 2.0, 1.0});
+    /// ```
+    pub fn causal_max(&self, axis: u32, window: u32) -> Option<Tensor<f32>>{
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+        if window == 0{
+            return None;
+        }
+
+        let mut return_data: Vec<f32> = vec![0.0; self.get_data().len()];
+
+        for o in 0..outer{
+            for j in 0..inner{
+                for i in 0..length{
+                    let begin = (i + 1).saturating_sub(window);
+
+                    let mut max = f32::NEG_INFINITY;
+                    for k in begin..=i{
+                        max = max.max(self.get_data()[(o*length*inner + k*inner + j) as usize]);
+                    }
+                    return_data[(o*length*inner + i*inner + j) as usize] = max;
+                }
+            }
+        }
+
+        Tensor::from_data(&return_data, self.get_shape())
+    }
+
+    /// Returns mean of each full window along axis,
+    /// with the axis shorter by window-1
+    /// None if axis out of range, window is 0 or longer than the axis
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0], &[5]).unwrap();
+    ///
+    /// //b = [2.0, 3.0, 4.0]
+    /// let b: Tensor<f32> = a.moving_average(3, 0).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{2.0, 3.0, 4.0});
+    /// ```
+    pub fn moving_average(&self, window: u32, axis: u32) -> Option<Tensor<f32>>{
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+        if window == 0 || window > length{
+            return None;
+        }
+
+        let output_length = length - window + 1;
+        let mut return_data: Vec<f32> = Vec::with_capacity((outer * output_length * inner) as usize);
+
+        for o in 0..outer{
+            for i in 0..output_length{
+                for j in 0..inner{
+                    let mut sum: f32 = 0.0;
+                    for k in i..i+window{
+                        sum += self.get_data()[(o*length*inner + k*inner + j) as usize];
+                    }
+                    return_data.push(sum / window as f32);
+                }
+            }
+        }
+
+        let mut output_shape = self.get_shape().clone();
+        output_shape[axis as usize] = output_length;
+
+        Tensor::from_data(&return_data, &output_shape)
+    }
+
+    /// Sum of each window of elements along axis,
+    /// computed from prefix sums in O(n) for any window size
+    /// output axis has length - window + 1 elements
+    /// None if axis is out of range, window is 0 or bigger than axis
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0], &[5]).unwrap();
+    ///
+    /// //b = [6.0, 9.0, 12.0]
+    /// let b: Tensor<f32> = a.rolling_sum(3, 0).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{6.0, 9.0, 12.0});
+    /// ```
+    pub fn rolling_sum(&self, window: u32, axis: u32) -> Option<Tensor<f32>>{
+        let (outer, length, inner) = split_axis(self.get_shape(), axis)?;
+        if window == 0 || window > length{
+            return None;
+        }
+
+        let output_length = length - window + 1;
+        let mut return_data: Vec<f32> = vec![0.0; (outer * output_length * inner) as usize];
+        let mut prefix: Vec<f64> = vec![0.0; (length + 1) as usize];
+
+        for o in 0..outer{
+            for j in 0..inner{
+                for k in 0..length{
+                    let value = self.get_data()[(o*length*inner + k*inner + j) as usize];
+                    prefix[(k + 1) as usize] = prefix[k as usize] + value as f64;
+                }
+                for i in 0..output_length{
+                    let sum = prefix[(i + window) as usize] - prefix[i as usize];
+                    return_data[(o*output_length*inner + i*inner + j) as usize] = sum as f32;
+                }
+            }
+        }
+
+        let mut output_shape = self.get_shape().clone();
+        output_shape[axis as usize] = output_length;
+
+        Tensor::from_data(&return_data, &output_shape)
+    }
+
+    /// Returns matrix [num_windows, window] of windows
+    /// of vector moved by step
+    /// None if tensor is not a vector, window or step is 0, or window is longer than vector
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0], &[5]).unwrap();
+    ///
+    /// //b =
+    /// //[1.0, 2.0, 3.0]
+    /// //[3.0, 4.0, 5.0]
+    /// let b: Tensor<f32> = a.sliding_windows(3, 2).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{1.0, 2.0, 3.0, 3.0, 4.0, 5.0});
+    /// assert_eq!(b.get_shape(), &vec!{2, 3});
+    /// ```
+    pub fn sliding_windows(&self, window: u32, step: u32) -> Option<Tensor<f32>>{
+        if self.get_shape().len() != 1{
+            return None;
+        }
+        let length = self.get_shape()[0];
+        if window == 0 || step == 0 || window > length{
+            return None;
+        }
+
+        let num_windows = (length - window) / step + 1;
+        let mut return_data: Vec<f32> = Vec::with_capacity((num_windows * window) as usize);
+
+        for w in 0..num_windows{
+            let begin = (w * step) as usize;
+            return_data.extend_from_slice(&self.get_data()[begin..begin + window as usize]);
+        }
+
+        Tensor::from_data(&return_data, &[num_windows, window])
+    }
+}
@@ -0,0 +1,95 @@
+use crate::tensor::*;
+
+/// error function, Abramowitz-Stegun 7.1.26 approximation
+/// with absolute error below 1.5e-7
+fn erf(x: f32) -> f32{
+    let sign = x.signum();
+    let x = x.abs() as f64;
+
+    let t = 1.0 / (1.0 + 0.3275911 * x);
+    let poly = t * (0.254829592 + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+
+    (sign as f64 * (1.0 - poly * (-x * x).exp())) as f32
+}
+
+impl Tensor<f32>{
+    /// Returns a tensor with data transformed using error function
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[-1.0, 0.0, 1.0], &[3]).unwrap();
+    /// let b = a.erf();
+    ///
+    /// b.assert_approx_eq(&Tensor::from_data(&[-0.8427008, 0.0, 0.8427008], &[3]).unwrap(), 1e-6);
+    /// ```
+    pub fn erf(&self) -> Tensor<f32>{
+        self.map(|val| erf(*val))
+    }
+
+    /// Returns a tensor with data transformed using complementary error function 1 - erf(x)
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[-1.0, 0.0, 1.0], &[3]).unwrap();
+    /// let b = a.erfc();
+    ///
+    /// b.assert_approx_eq(&Tensor::from_data(&[1.8427008, 1.0, 0.1572992], &[3]).unwrap(), 1e-6);
+    /// ```
+    pub fn erfc(&self) -> Tensor<f32>{
+        self.map(|val| 1.0 - erf(*val))
+    }
+
+    /// Returns a tensor with cumulative distribution function
+    /// of normal distribution with mean and std
+    /// None if std is not positive
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 3.0], &[2]).unwrap();
+    ///
+    /// //b = [0.5, 0.9772499]
+    /// let b = a.normal_cdf(1.0, 1.0).unwrap();
+    ///
+    /// b.assert_approx_eq(&Tensor::from_data(&[0.5, 0.9772499], &[2]).unwrap(), 1e-6);
+    /// ```
+    pub fn normal_cdf(&self, mean: f32, std: f32) -> Option<Tensor<f32>>{
+        if std.is_nan() || std <= 0.0{
+            return None;
+        }
+
+        Some(self.map(|val| 0.5 * (1.0 + erf((val - mean) / (std * std::f32::consts::SQRT_2)))))
+    }
+
+    /// Returns a tensor with probability density function
+    /// of normal distribution with mean and std
+    /// None if std is not positive
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[0.0], &[1]).unwrap();
+    ///
+    /// //b = [1 / sqrt(2 * pi)]
+    /// let b = a.normal_pdf(0.0, 1.0).unwrap();
+    ///
+    /// assert!((b.get_data()[0] - 0.3989423).abs() < 1e-6);
+    /// ```
+    pub fn normal_pdf(&self, mean: f32, std: f32) -> Option<Tensor<f32>>{
+        if std.is_nan() || std <= 0.0{
+            return None;
+        }
+
+        let norm = 1.0 / (std * (2.0 * std::f32::consts::PI).sqrt());
+        Some(self.map(|val| {
+            let z = (val - mean) / std;
+            norm * (-0.5 * z * z).exp()
+        }))
+    }
+}
@@ -2,3 +2,5 @@ pub mod math;
 pub mod subtypes;
 pub mod machine_learning;
 pub mod broadcasting;
+pub mod manipulation;
+pub mod random;
@@ -0,0 +1,85 @@
+use crate::tensor::*;
+
+/// Small xorshift64* generator,
+/// so same seed gives same values on every platform
+struct XorShift{
+    state: u64,
+}
+
+impl XorShift{
+    fn new(seed: u64) -> XorShift{
+        // splitmix64 step, so seeds 0 and close seeds give good states
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+
+        if z == 0{
+            z = 0x9E37_79B9_7F4A_7C15;
+        }
+
+        XorShift{ state: z }
+    }
+
+    fn next_u64(&mut self) -> u64{
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// uniform value in [0, 1) from upper 24 bits
+    fn next_f32(&mut self) -> f32{
+        (self.next_u64() >> 40) as f32 / (1u32 << 24) as f32
+    }
+}
+
+impl Tensor<f32>{
+    /// Creates tensor with shape filled with uniform values in [0, 1)
+    /// Same seed and shape always give the same data
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::random(&[2, 3], 42);
+    /// let b: Tensor<f32> = Tensor::random(&[2, 3], 42);
+    ///
+    /// assert_eq!(a.get_data(), b.get_data());
+    /// assert!(a.get_data().iter().all(|v| *v >= 0.0 && *v < 1.0));
+    /// ```
+    pub fn random(shape: &[u32], seed: u64) -> Tensor<f32>{
+        let mut rng = XorShift::new(seed);
+        let full_size: u32 = shape.iter().product();
+
+        let mut return_data: Vec<f32> = Vec::with_capacity(full_size as usize);
+        for _ in 0..full_size{
+            return_data.push(rng.next_f32());
+        }
+
+        Tensor::from_data(&return_data, shape).unwrap()
+    }
+
+    /// Creates tensor with shape filled with uniform values in [low, high)
+    /// Same seed and shape always give the same data
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::random_range(&[4, 4], 7, -1.0, 1.0);
+    ///
+    /// assert!(a.get_data().iter().all(|v| *v >= -1.0 && *v < 1.0));
+    /// ```
+    pub fn random_range(shape: &[u32], seed: u64, low: f32, high: f32) -> Tensor<f32>{
+        let mut tensor = Tensor::random(shape, seed);
+
+        let mut return_data: Vec<f32> = Vec::with_capacity(tensor.get_data().len());
+        for i in 0..tensor.get_data().len(){
+            return_data.push(low + tensor.get_data()[i] * (high - low));
+        }
+        tensor.set_data(&return_data);
+
+        tensor
+    }
+}
@@ -1,4 +1,47 @@
 use crate::tensor::*;
+use crate::error::TensorError;
+
+/// Solves a * x = b for square matrix a[n, n] (row-major) and b[n]
+/// using gaussian elimination with partial pivoting
+/// None if matrix is singular
+fn solve_linear(mut a: Vec<f32>, mut b: Vec<f32>, n: usize) -> Option<Vec<f32>>{
+    for col in 0..n{
+        let mut pivot = col;
+        for row in col+1..n{
+            if a[row*n + col].abs() > a[pivot*n + col].abs(){
+                pivot = row;
+            }
+        }
+        if a[pivot*n + col].abs() < 1e-9{
+            return None;
+        }
+        if pivot != col{
+            for k in 0..n{
+                a.swap(col*n + k, pivot*n + k);
+            }
+            b.swap(col, pivot);
+        }
+
+        for row in col+1..n{
+            let factor = a[row*n + col] / a[col*n + col];
+            for k in col..n{
+                a[row*n + k] -= factor * a[col*n + k];
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    let mut x: Vec<f32> = vec![0.0; n];
+    for row in (0..n).rev(){
+        let mut value = b[row];
+        for k in row+1..n{
+            value -= a[row*n + k] * x[k];
+        }
+        x[row] = value / a[row*n + row];
+    }
+
+    Some(x)
+}
 
 impl<T: Default + Clone> Tensor<T>{
     /// Get matrix on position
@@ -69,7 +112,7 @@ impl<T: Default + Clone> Tensor<T>{
 
     /// ```
     pub fn matrix_row(&self, row: u32) -> Option<Tensor<T>>{
-        if self.get_shape().len() != 2{
+        if self.rank() != 2{
             return None;
         }
         if row >= self.get_shape()[0]{
@@ -106,7 +149,7 @@ impl<T: Default + Clone> Tensor<T>{
     /// assert_eq!(result.get_shape(), expected.get_shape());
     /// ```
     pub fn matrix_col(&self, col: u32) -> Option<Tensor<T>>{
-        if self.get_shape().len() != 2{
+        if self.rank() != 2{
             return None;
         }
         if col>= self.get_shape()[1]{
@@ -144,7 +187,7 @@ impl<T: Default + Clone> Tensor<T>{
     /// assert_eq!(result.get_shape(), &expected_sizes);
     /// ```
     pub fn matrix_transpose(&self) -> Option<Tensor<T>>{
-        if self.get_shape().len() != 2{
+        if self.rank() != 2{
             return None;
         }
 
@@ -161,6 +204,45 @@ impl<T: Default + Clone> Tensor<T>{
 
         Some(Tensor::from_data(&return_data, &new_sizes).unwrap())
     }
+
+    /// Returns only the rows of matrix, where keep is true
+    /// keep need to have shape [rows]
+    /// or None
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let data: Vec<f32> = vec!{1.0, 2.0, 3.0, 4.0, 5.0, 6.0};
+    /// let tensor: Tensor<f32> = Tensor::from_data(&data, &[3, 2]).unwrap();
+    /// let keep: Tensor<bool> = Tensor::from_data(&[true, false, true], &[3]).unwrap();
+    ///
+    /// let result = tensor.filter_rows(&keep).unwrap();
+    ///
+    /// assert_eq!(result.get_data(), &vec!{1.0, 2.0, 5.0, 6.0});
+    /// assert_eq!(result.get_shape(), &vec!{2, 2});
+    /// ```
+    pub fn filter_rows(&self, keep: &Tensor<bool>) -> Option<Tensor<T>>{
+        if self.rank() != 2{
+            return None;
+        }
+        if keep.get_shape() != &vec!{self.get_shape()[0]}{
+            return None;
+        }
+
+        let row_size = self.get_shape()[1] as usize;
+        let mut return_data: Vec<T> = Vec::with_capacity(self.get_data().len());
+        let mut rows: u32 = 0;
+
+        for row in 0..self.get_shape()[0] as usize{
+            if keep.get_data()[row]{
+                return_data.extend_from_slice(&self.get_data()[row*row_size..(row+1)*row_size]);
+                rows += 1;
+            }
+        }
+
+        Tensor::from_data(&return_data, &[rows, row_size as u32])
+    }
 }
 
 impl<T> Tensor<T>
@@ -185,7 +267,7 @@ where
     /// ```
     pub fn matrix_to_string(&self) -> Option<String>{
 
-        if self.get_shape().len() != 2{
+        if self.rank() != 2{
             return None;
         }
         
@@ -209,7 +291,10 @@ where
     }
 }
 
-impl Tensor<f32>{
+impl<T> Tensor<T>
+where
+    T: Default + Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
+{
     /// Persorms matrix multiplication on matrix with another matrix
     ///
     /// # Example
@@ -231,19 +316,48 @@ impl Tensor<f32>{
     ///
     /// assert_eq!(result.get_data(), &expected_data);
     /// assert_eq!(result.get_shape(), &expected_sizes);
+    ///
+    /// //works for integer matrices, without rounding
+    /// let a: Tensor<i32> = Tensor::from_data(&[1, -2, 3, 4], &[2, 2]).unwrap();
+    /// let b: Tensor<i32> = Tensor::from_data(&[100000, 7, -3, 5], &[2, 2]).unwrap();
+    ///
+    /// let c: Tensor<i32> = a.matrix_mul(&b).unwrap();
+    ///
+    /// assert_eq!(c.get_data(), &vec!{100006, -3, 299988, 41});
     /// ```
-    pub fn matrix_mul(&self, tens2: &Tensor<f32>) -> Option<Tensor<f32>>{
-        if self.get_shape().len() != 2{
-            return None;
+    pub fn matrix_mul(&self, tens2: &Tensor<T>) -> Option<Tensor<T>>{
+        self.matrix_mul_checked(tens2).ok()
+    }
+
+    /// Persorms matrix multiplication on matrix with another matrix
+    /// or error with reason why matrices can not be multiplied
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::fill(1.0, &[2, 3]);
+    /// let b: Tensor<f32> = Tensor::fill(1.0, &[2, 4]);
+    ///
+    /// let result = a.matrix_mul_checked(&b);
+    ///
+    /// assert_eq!(result.err(), Some(TensorError::ShapeMismatch{ expected: vec!{3, 4}, got: vec!{2, 4} }));
+    /// ```
+    pub fn matrix_mul_checked(&self, tens2: &Tensor<T>) -> Result<Tensor<T>, TensorError>{
+        if self.rank() != 2{
+            return Err(TensorError::RankMismatch{ expected: 2, got: self.rank() });
         }
-        if self.get_shape().len() != tens2.get_shape().len(){
-            return None;
+        if tens2.rank() != 2{
+            return Err(TensorError::RankMismatch{ expected: 2, got: tens2.rank() });
         }
         if self.get_shape()[1] != tens2.get_shape()[0]{
-            return None;
+            return Err(TensorError::ShapeMismatch{
+                expected: vec!{self.get_shape()[1], tens2.get_shape()[1]},
+                got: tens2.get_shape().clone(),
+            });
         }
 
-        let mut return_data: Vec<f32> = Vec::with_capacity((self.get_shape()[0] * tens2.get_shape()[1]) as usize);
+        let mut return_data: Vec<T> = Vec::with_capacity((self.get_shape()[0] * tens2.get_shape()[1]) as usize);
         for i in 0..self.get_shape()[0]{
             for j in 0..tens2.get_shape()[1]{
 
@@ -258,7 +372,540 @@ impl Tensor<f32>{
 
         let sizes = vec!{self.get_shape()[0], tens2.get_shape()[1]};
         
-        Tensor::from_data(&return_data, &sizes)
+        Tensor::from_data_checked(&return_data, &sizes)
+    }
+}
+
+impl Tensor<f32>{
+    /// Multiplies matrix [m, k] by transposed matrix [n, k]
+    /// without allocating the transposed matrix
+    /// None if tensors are not matrices or k is different
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+    /// let b: Tensor<f32> = Tensor::from_data(&[1.0, 0.0, 1.0, 1.0, 0.0, 2.0], &[3, 2]).unwrap();
+    ///
+    /// //c =
+    /// //[1.0, 3.0, 4.0]
+    /// //[3.0, 7.0, 8.0]
+    /// let c: Tensor<f32> = a.matmul_bt(&b).unwrap();
+    ///
+    /// assert_eq!(c.get_data(), &vec!{1.0, 3.0, 4.0, 3.0, 7.0, 8.0});
+    /// assert_eq!(c.get_shape(), &vec!{2, 3});
+    /// ```
+    pub fn matmul_bt(&self, b: &Tensor<f32>) -> Option<Tensor<f32>>{
+        if self.rank() != 2 || b.rank() != 2{
+            return None;
+        }
+        if self.get_shape()[1] != b.get_shape()[1]{
+            return None;
+        }
+
+        let m = self.get_shape()[0] as usize;
+        let n = b.get_shape()[0] as usize;
+        let k = self.get_shape()[1] as usize;
+
+        let a_data = self.get_data();
+        let b_data = b.get_data();
+
+        let mut return_data: Vec<f32> = Vec::with_capacity(m * n);
+        for i in 0..m{
+            let a_row = &a_data[i*k..(i+1)*k];
+            for j in 0..n{
+                let b_row = &b_data[j*k..(j+1)*k];
+
+                let mut sum = 0.0;
+                for l in 0..k{
+                    sum += a_row[l] * b_row[l];
+                }
+                return_data.push(sum);
+            }
+        }
+
+        Tensor::from_data(&return_data, &[m as u32, n as u32])
+    }
+
+    /// Multiplies matrix [m, n] by vector [n]
+    /// returns vector [m]
+    /// None if tensors are not matrix and vector, or n is different
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]).unwrap();
+    /// let v: Tensor<f32> = Tensor::from_data(&[1.0, 0.0, -1.0], &[3]).unwrap();
+    ///
+    /// //b = [-2.0, -2.0]
+    /// let b: Tensor<f32> = a.matvec(&v).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{-2.0, -2.0});
+    /// assert_eq!(b.get_shape(), &vec!{2});
+    /// ```
+    pub fn matvec(&self, v: &Tensor<f32>) -> Option<Tensor<f32>>{
+        if self.rank() != 2 || v.rank() != 1{
+            return None;
+        }
+        if self.get_shape()[1] != v.get_shape()[0]{
+            return None;
+        }
+
+        let m = self.get_shape()[0] as usize;
+        let n = self.get_shape()[1] as usize;
+
+        let mut return_data: Vec<f32> = Vec::with_capacity(m);
+        for i in 0..m{
+            let mut sum = 0.0;
+            for k in 0..n{
+                sum += self.get_data()[i*n + k] * v.get_data()[k];
+            }
+            return_data.push(sum);
+        }
+
+        Tensor::from_data(&return_data, &[m as u32])
+    }
+
+    /// Inverse of square matrix
+    /// using gauss-jordan elimination with partial pivoting
+    /// None if tensor is not square matrix or matrix is singular
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[4.0, 7.0, 2.0, 6.0], &[2, 2]).unwrap();
+    ///
+    /// //b =
+    /// //[0.6, -0.7]
+    /// //[-0.2, 0.4]
+    /// let b: Tensor<f32> = a.matrix_inverse().unwrap();
+    ///
+    /// let expected: Tensor<f32> = Tensor::from_data(&[0.6, -0.7, -0.2, 0.4], &[2, 2]).unwrap();
+    /// b.assert_approx_eq(&expected, 1e-5);
+    ///
+    /// let c: Tensor<f32> = Tensor::from_data(&[2.0, 0.0, 0.0, 0.0, 4.0, 0.0, 1.0, 0.0, 1.0], &[3, 3]).unwrap();
+    ///
+    /// //d =
+    /// //[0.5, 0.0, 0.0]
+    /// //[0.0, 0.25, 0.0]
+    /// //[-0.5, 0.0, 1.0]
+    /// let d: Tensor<f32> = c.matrix_inverse().unwrap();
+    ///
+    /// let expected: Tensor<f32> = Tensor::from_data(&[0.5, 0.0, 0.0, 0.0, 0.25, 0.0, -0.5, 0.0, 1.0], &[3, 3]).unwrap();
+    /// d.assert_approx_eq(&expected, 1e-6);
+    ///
+    /// let singular: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 2.0, 4.0], &[2, 2]).unwrap();
+    /// assert!(singular.matrix_inverse().is_none());
+    /// ```
+    pub fn matrix_inverse(&self) -> Option<Tensor<f32>>{
+        if !self.is_square(){
+            return None;
+        }
+
+        let n = self.get_shape()[0] as usize;
+        let mut a: Vec<f32> = self.get_data().clone();
+        let mut inverse: Vec<f32> = vec![0.0; n*n];
+        for i in 0..n{
+            inverse[i*n + i] = 1.0;
+        }
+
+        for col in 0..n{
+            let mut pivot = col;
+            for row in col+1..n{
+                if a[row*n + col].abs() > a[pivot*n + col].abs(){
+                    pivot = row;
+                }
+            }
+            if a[pivot*n + col].abs() < 1e-9{
+                return None;
+            }
+            if pivot != col{
+                for k in 0..n{
+                    a.swap(col*n + k, pivot*n + k);
+                    inverse.swap(col*n + k, pivot*n + k);
+                }
+            }
+
+            let pivot_value = a[col*n + col];
+            for k in 0..n{
+                a[col*n + k] /= pivot_value;
+                inverse[col*n + k] /= pivot_value;
+            }
+
+            for row in 0..n{
+                if row == col{
+                    continue;
+                }
+                let factor = a[row*n + col];
+                for k in 0..n{
+                    a[row*n + k] -= factor * a[col*n + k];
+                    inverse[row*n + k] -= factor * inverse[col*n + k];
+                }
+            }
+        }
+
+        Tensor::from_data(&inverse, self.get_shape())
+    }
+
+    /// Determinant of square matrix
+    /// using lu decomposition with partial pivoting
+    /// None if tensor is not square matrix
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let data: Vec<f32> = vec!{
+    ///     2.0, 1.0, 0.0, 0.0,
+    ///     1.0, 3.0, 1.0, 0.0,
+    ///     0.0, 1.0, 4.0, 1.0,
+    ///     0.0, 0.0, 1.0, 5.0,
+    /// };
+    /// let a: Tensor<f32> = Tensor::from_data(&data, &[4, 4]).unwrap();
+    ///
+    /// assert!((a.determinant().unwrap() - 85.0).abs() < 1e-4);
+    /// ```
+    pub fn determinant(&self) -> Option<f32>{
+        self.determinant_checked().ok()
+    }
+
+    /// Determinant of square matrix
+    /// or error if tensor is not square matrix
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::fill(1.0, &[2, 3]);
+    ///
+    /// assert_eq!(a.determinant_checked(), Err(TensorError::NotSquare{ shape: vec!{2, 3} }));
+    /// ```
+    pub fn determinant_checked(&self) -> Result<f32, TensorError>{
+        if !self.is_square(){
+            return Err(TensorError::NotSquare{ shape: self.get_shape().clone() });
+        }
+
+        let n = self.get_shape()[0] as usize;
+        let mut a: Vec<f32> = self.get_data().clone();
+        let mut det: f32 = 1.0;
+
+        for col in 0..n{
+            let mut pivot = col;
+            for row in col+1..n{
+                if a[row*n + col].abs() > a[pivot*n + col].abs(){
+                    pivot = row;
+                }
+            }
+            if a[pivot*n + col] == 0.0{
+                return Ok(0.0);
+            }
+            if pivot != col{
+                for k in 0..n{
+                    a.swap(col*n + k, pivot*n + k);
+                }
+                det = -det;
+            }
+
+            det *= a[col*n + col];
+            for row in col+1..n{
+                let factor = a[row*n + col] / a[col*n + col];
+                for k in col..n{
+                    a[row*n + k] -= factor * a[col*n + k];
+                }
+            }
+        }
+
+        Ok(det)
+    }
+
+    /// Creates identity matrix [n, n]
+    /// with 1.0 on diagonal and 0.0 elsewhere
+    /// For n = 0 returns tensor with shape [0, 0] and no data
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// //a =
+    /// //[1.0, 0.0, 0.0]
+    /// //[0.0, 1.0, 0.0]
+    /// //[0.0, 0.0, 1.0]
+    /// let a: Tensor<f32> = Tensor::identity(3);
+    ///
+    /// assert_eq!(a.get_data(), &vec!{1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0});
+    /// assert_eq!(a.get_shape(), &vec!{3, 3});
+    /// ```
+    pub fn identity(n: u32) -> Tensor<f32>{
+        let mut return_data: Vec<f32> = vec![0.0; (n * n) as usize];
+        for i in 0..n{
+            return_data[(i*n + i) as usize] = 1.0;
+        }
+
+        Tensor::from_data(&return_data, &[n, n]).unwrap()
+    }
+
+    /// Creates toeplitz matrix [n, m] from first collumn [n] and first row [m]
+    /// with constant values on each diagonal
+    /// None if tensors are not vectors or first elements are different
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let col: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0], &[3]).unwrap();
+    /// let row: Tensor<f32> = Tensor::from_data(&[1.0, 4.0], &[2]).unwrap();
+    ///
+    /// //a =
+    /// //[1.0, 4.0]
+    /// //[2.0, 1.0]
+    /// //[3.0, 2.0]
+    /// let a: Tensor<f32> = Tensor::toeplitz(&col, &row).unwrap();
+    ///
+    /// assert_eq!(a.get_data(), &vec!{1.0, 4.0, 2.0, 1.0, 3.0, 2.0});
+    /// assert_eq!(a.get_shape(), &vec!{3, 2});
+    /// ```
+    pub fn toeplitz(first_col: &Tensor<f32>, first_row: &Tensor<f32>) -> Option<Tensor<f32>>{
+        if first_col.get_shape().len() != 1 || first_row.get_shape().len() != 1{
+            return None;
+        }
+        if first_col.get_data().is_empty() || first_row.get_data().is_empty(){
+            return None;
+        }
+        if first_col.get_data()[0] != first_row.get_data()[0]{
+            return None;
+        }
+
+        let rows = first_col.get_shape()[0];
+        let cols = first_row.get_shape()[0];
+        let mut return_data: Vec<f32> = Vec::with_capacity((rows * cols) as usize);
+
+        for i in 0..rows{
+            for j in 0..cols{
+                if i >= j{
+                    return_data.push(first_col.get_data()[(i - j) as usize]);
+                }
+                else{
+                    return_data.push(first_row.get_data()[(j - i) as usize]);
+                }
+            }
+        }
+
+        Tensor::from_data(&return_data, &[rows, cols])
+    }
+
+    /// Creates vandermonde matrix [n, degree+1] from vector x[n]
+    /// where collumn j is x raised to the power j
+    /// None if x is not a vector
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let x: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0], &[3]).unwrap();
+    ///
+    /// //a =
+    /// //[1.0, 1.0, 1.0]
+    /// //[1.0, 2.0, 4.0]
+    /// //[1.0, 3.0, 9.0]
+    /// let a: Tensor<f32> = Tensor::vandermonde(&x, 2).unwrap();
+    ///
+    /// assert_eq!(a.get_data(), &vec!{1.0, 1.0, 1.0, 1.0, 2.0, 4.0, 1.0, 3.0, 9.0});
+    /// assert_eq!(a.get_shape(), &vec!{3, 3});
+    /// ```
+    pub fn vandermonde(x: &Tensor<f32>, degree: u32) -> Option<Tensor<f32>>{
+        if x.get_shape().len() != 1{
+            return None;
+        }
+
+        let rows = x.get_shape()[0];
+        let cols = degree + 1;
+        let mut return_data: Vec<f32> = Vec::with_capacity((rows * cols) as usize);
+
+        for i in 0..rows as usize{
+            let mut power: f32 = 1.0;
+            for _ in 0..cols{
+                return_data.push(power);
+                power *= x.get_data()[i];
+            }
+        }
+
+        Tensor::from_data(&return_data, &[rows, cols])
+    }
+
+    /// Returns coefficients [degree+1] of polynomial, lowest power first,
+    /// that best fits points (x, y) in least squares sense
+    /// None if x and y are not vectors of the same length or there are not enough points
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let x: Tensor<f32> = Tensor::from_data(&[0.0, 1.0, 2.0], &[3]).unwrap();
+    /// let y: Tensor<f32> = Tensor::from_data(&[1.0, 3.0, 5.0], &[3]).unwrap();
+    ///
+    /// //y = 1 + 2x
+    /// let coefficients: Tensor<f32> = Tensor::polyfit(&x, &y, 1).unwrap();
+    ///
+    /// assert!((coefficients.get_data()[0] - 1.0).abs() < 1e-4);
+    /// assert!((coefficients.get_data()[1] - 2.0).abs() < 1e-4);
+    /// ```
+    pub fn polyfit(x: &Tensor<f32>, y: &Tensor<f32>, degree: u32) -> Option<Tensor<f32>>{
+        if x.get_shape().len() != 1 || x.get_shape() != y.get_shape(){
+            return None;
+        }
+
+        let vandermonde = Tensor::vandermonde(x, degree)?;
+        let vandermonde_t = vandermonde.matrix_transpose()?;
+
+        let mut y_col = y.clone();
+        y_col.set_shape(&[y.get_shape()[0], 1]);
+
+        let normal_matrix = vandermonde_t.matrix_mul(&vandermonde)?;
+        let normal_vector = vandermonde_t.matrix_mul(&y_col)?;
+
+        let n = (degree + 1) as usize;
+        let coefficients = solve_linear(normal_matrix.get_data().clone(), normal_vector.get_data().clone(), n)?;
+
+        Tensor::from_data(&coefficients, &[degree + 1])
+    }
+
+    /// Returns vector [batch] of dot products of each row
+    /// of two matrices with the same shape [batch, d]
+    /// or None
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+    /// let b: Tensor<f32> = Tensor::from_data(&[1.0, 1.0, 0.0, 2.0], &[2, 2]).unwrap();
+    ///
+    /// //c = [3.0, 8.0]
+    /// let c: Tensor<f32> = a.batched_dot(&b).unwrap();
+    ///
+    /// assert_eq!(c.get_data(), &vec!{3.0, 8.0});
+    /// assert_eq!(c.get_shape(), &vec!{2});
+    /// ```
+    pub fn batched_dot(&self, tens2: &Tensor<f32>) -> Option<Tensor<f32>>{
+        if self.rank() != 2{
+            return None;
+        }
+        if self.get_shape() != tens2.get_shape(){
+            return None;
+        }
+
+        let batch = self.get_shape()[0];
+        let row_size = self.get_shape()[1] as usize;
+        let mut return_data: Vec<f32> = Vec::with_capacity(batch as usize);
+
+        for row in 0..batch as usize{
+            let mut dot: f32 = 0.0;
+            for i in row*row_size..(row+1)*row_size{
+                dot += self.get_data()[i] * tens2.get_data()[i];
+            }
+            return_data.push(dot);
+        }
+
+        Tensor::from_data(&return_data, &[batch])
+    }
+
+    /// Returns matrix [n, n] of cosine similarities between each pair of rows
+    /// of matrix [n, d]
+    /// or None
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 0.0, 0.0, 2.0], &[2, 2]).unwrap();
+    ///
+    /// //b =
+    /// //[1.0, 0.0]
+    /// //[0.0, 1.0]
+    /// let b: Tensor<f32> = a.pairwise_cosine().unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{1.0, 0.0, 0.0, 1.0});
+    /// ```
+    pub fn pairwise_cosine(&self) -> Option<Tensor<f32>>{
+        if self.rank() != 2{
+            return None;
+        }
+
+        let normalized = self.normalize_rows();
+
+        normalized.matrix_mul(&normalized.matrix_transpose()?)
+    }
+
+    /// Returns matrix [batch, a*b] of all pairwise products of elements
+    /// in each row of matrices [batch, a] and [batch, b]
+    /// or None
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0], &[1, 2]).unwrap();
+    /// let b: Tensor<f32> = Tensor::from_data(&[3.0, 4.0, 5.0], &[1, 3]).unwrap();
+    ///
+    /// //c = [3.0, 4.0, 5.0, 6.0, 8.0, 10.0]
+    /// let c: Tensor<f32> = a.cross_features(&b).unwrap();
+    ///
+    /// assert_eq!(c.get_data(), &vec!{3.0, 4.0, 5.0, 6.0, 8.0, 10.0});
+    /// assert_eq!(c.get_shape(), &vec!{1, 6});
+    /// ```
+    pub fn cross_features(&self, tens2: &Tensor<f32>) -> Option<Tensor<f32>>{
+        if self.rank() != 2 || tens2.rank() != 2{
+            return None;
+        }
+        if self.get_shape()[0] != tens2.get_shape()[0]{
+            return None;
+        }
+
+        let batch = self.get_shape()[0] as usize;
+        let a_size = self.get_shape()[1] as usize;
+        let b_size = tens2.get_shape()[1] as usize;
+
+        let mut return_data: Vec<f32> = Vec::with_capacity(batch * a_size * b_size);
+
+        for row in 0..batch{
+            for i in 0..a_size{
+                for j in 0..b_size{
+                    return_data.push(self.get_data()[row*a_size + i] * tens2.get_data()[row*b_size + j]);
+                }
+            }
+        }
+
+        Tensor::from_data(&return_data, &[batch as u32, (a_size * b_size) as u32])
+    }
+
+    /// Returns tensor [batch, m, n] of outer products of each row
+    /// of matrices [batch, m] and [batch, n]
+    /// or None
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0], &[1, 2]).unwrap();
+    /// let b: Tensor<f32> = Tensor::from_data(&[3.0, 4.0], &[1, 2]).unwrap();
+    ///
+    /// //c =
+    /// //[[3.0, 4.0]
+    /// // [6.0, 8.0]]
+    /// let c: Tensor<f32> = a.batched_outer(&b).unwrap();
+    ///
+    /// assert_eq!(c.get_data(), &vec!{3.0, 4.0, 6.0, 8.0});
+    /// assert_eq!(c.get_shape(), &vec!{1, 2, 2});
+    /// ```
+    pub fn batched_outer(&self, tens2: &Tensor<f32>) -> Option<Tensor<f32>>{
+        let mut crossed = self.cross_features(tens2)?;
+
+        crossed.set_shape(&[self.get_shape()[0], self.get_shape()[1], tens2.get_shape()[1]]);
+
+        Some(crossed)
     }
 }
 
@@ -287,7 +934,7 @@ where
     /// assert_eq!(result.get_shape(), &expected_sizes);
     /// ```
     pub fn matrix_col_sum(&self) -> Option<Tensor<T>>{
-        if self.get_shape().len() != 2{
+        if self.rank() != 2{
             return None;
         }
         let mut new_data: Vec<T> = Vec::with_capacity(self.get_shape()[0] as usize);
@@ -325,7 +972,7 @@ where
     /// assert_eq!(result.get_shape(), &expected_sizes);
     /// ```
     pub fn matrix_row_sum(&self) -> Option<Tensor<T>>{
-        if self.get_shape().len() != 2{
+        if self.rank() != 2{
             return None;
         }
         let mut new_data: Vec<T> = Vec::with_capacity(self.get_shape()[0] as usize);
@@ -368,7 +1015,7 @@ where
     /// assert_eq!(result.get_shape(), &expected_sizes);
     /// ```
     pub fn matrix_col_prod(&self) -> Option<Tensor<T>>{
-        if self.get_shape().len() != 2{
+        if self.rank() != 2{
             return None;
         }
         let mut new_data: Vec<T> = Vec::with_capacity(self.get_shape()[0] as usize);
@@ -406,7 +1053,7 @@ where
     /// assert_eq!(result.get_shape(), &expected_sizes);
     /// ```
     pub fn matrix_row_prod(&self) -> Option<Tensor<T>>{
-        if self.get_shape().len() != 2{
+        if self.rank() != 2{
             return None;
         }
         let mut new_data: Vec<T> = Vec::with_capacity(self.get_shape()[0] as usize);
@@ -1,3 +1,4 @@
 pub mod matrix;
 pub mod vector;
 pub mod helpers;
+pub mod sparse;
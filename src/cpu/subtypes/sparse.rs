@@ -0,0 +1,60 @@
+use crate::tensor::*;
+
+impl Tensor<f32>{
+    /// Returns positions and values of all non zero elements
+    /// in row-major order
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[0.0, 2.0, 0.0, 4.0], &[2, 2]).unwrap();
+    ///
+    /// let (coords, values) = a.to_sparse_coo();
+    ///
+    /// assert_eq!(coords, vec!{vec!{0, 1}, vec!{1, 1}});
+    /// assert_eq!(values, vec!{2.0, 4.0});
+    /// ```
+    pub fn to_sparse_coo(&self) -> (Vec<Vec<u32>>, Vec<f32>){
+        let mut coords: Vec<Vec<u32>> = Vec::new();
+        let mut values: Vec<f32> = Vec::new();
+
+        for (pos, val) in self.indexed_iter(){
+            if *val != 0.0{
+                coords.push(pos);
+                values.push(*val);
+            }
+        }
+
+        (coords, values)
+    }
+
+    /// Creates tensor with shape from positions and values of non zero elements
+    /// None if coords and values have different length, or position is out of shape
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let coords: Vec<Vec<u32>> = vec!{vec!{0, 1}, vec!{1, 1}};
+    /// let values: Vec<f32> = vec!{2.0, 4.0};
+    ///
+    /// let a: Tensor<f32> = Tensor::from_sparse_coo(&coords, &values, &[2, 2]).unwrap();
+    ///
+    /// assert_eq!(a.get_data(), &vec!{0.0, 2.0, 0.0, 4.0});
+    /// ```
+    pub fn from_sparse_coo(coords: &[Vec<u32>], values: &[f32], shape: &[u32]) -> Option<Tensor<f32>>{
+        if coords.len() != values.len(){
+            return None;
+        }
+
+        let mut tensor: Tensor<f32> = Tensor::new(shape);
+
+        for i in 0..coords.len(){
+            tensor.value(&coords[i])?;
+            tensor.set(values[i], &coords[i]);
+        }
+
+        Some(tensor)
+    }
+}
@@ -51,7 +51,47 @@ impl<T: Default + Clone> Tensor<T>{
     }
 }
 
-impl Tensor<f32>{
+impl<T: Default + Copy> Tensor<T>{
+    /// Returns matrix [m, n] of f(a[i], b[j])
+    /// for one dimensional tensors a[m] and b[n]
+    /// or None
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0], &[2]).unwrap();
+    /// let b: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0], &[3]).unwrap();
+    ///
+    /// //result =
+    /// //[1.0, 2.0, 3.0]
+    /// //[2.0, 4.0, 6.0]
+    /// let result = a.outer_apply(&b, |x, y| x * y).unwrap();
+    ///
+    /// assert_eq!(result.get_data(), &vec!{1.0, 2.0, 3.0, 2.0, 4.0, 6.0});
+    /// assert_eq!(result.get_shape(), &vec!{2, 3});
+    /// ```
+    pub fn outer_apply<F: Fn(T, T) -> T>(&self, tens2: &Tensor<T>, f: F) -> Option<Tensor<T>>{
+        if self.get_shape().len() != 1 || tens2.get_shape().len() != 1{
+            return None;
+        }
+
+        let mut return_data: Vec<T> = Vec::with_capacity(self.get_data().len() * tens2.get_data().len());
+
+        for i in 0..self.get_data().len(){
+            for j in 0..tens2.get_data().len(){
+                return_data.push(f(self.get_data()[i], tens2.get_data()[j]));
+            }
+        }
+
+        Tensor::from_data(&return_data, &[self.get_shape()[0], tens2.get_shape()[0]])
+    }
+}
+
+impl<T> Tensor<T>
+where
+    T: Default + Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
+{
     /// Get dot product from tensors if tensors have one dimenstion
     /// and have same size
     ///
@@ -71,7 +111,7 @@ impl Tensor<f32>{
     ///
     /// assert_eq!(result, expected);
     /// ```
-    pub fn dot_product(&self, tens2: &Tensor<f32>) -> Option<f32>{
+    pub fn dot_product(&self, tens2: &Tensor<T>) -> Option<T>{
         if self.get_shape().len() != 1{
             return None;
         }
@@ -79,11 +119,35 @@ impl Tensor<f32>{
             return None;
         }
         
-        let mut dot: f32 = 0.0;
+        let mut dot: T = T::default();
         for i in 0..self.get_shape()[0] as u32{
-            dot += self.value(&[i]).unwrap() * tens2.value(&[i]).unwrap();
+            dot = dot + *self.value(&[i]).unwrap() * *tens2.value(&[i]).unwrap();
         }
 
         Some(dot)
     }
 }
+
+impl Tensor<f32>{
+    /// Returns matrix [m, n] of a[i] + b[j]
+    /// for one dimensional tensors a[m] and b[n]
+    /// or None
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[0.0, 10.0], &[2]).unwrap();
+    /// let b: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0], &[3]).unwrap();
+    ///
+    /// //result =
+    /// //[1.0, 2.0, 3.0]
+    /// //[11.0, 12.0, 13.0]
+    /// let result = a.sum_outer(&b).unwrap();
+    ///
+    /// assert_eq!(result.get_data(), &vec!{1.0, 2.0, 3.0, 11.0, 12.0, 13.0});
+    /// ```
+    pub fn sum_outer(&self, tens2: &Tensor<f32>) -> Option<Tensor<f32>>{
+        self.outer_apply(tens2, |x, y| x + y)
+    }
+}
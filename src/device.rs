@@ -0,0 +1,45 @@
+use crate::tensor::Tensor;
+
+/// Device on which tensor data is stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Device{
+    Cpu,
+    Gpu,
+}
+
+impl Tensor<f32>{
+    /// Device that holds tensor data
+    ///
+    /// Tensor data is always stored on cpu for now,
+    /// gpu operations upload it when dispatched
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::fill(1.0, &[2, 2]);
+    ///
+    /// assert_eq!(a.device(), Device::Cpu);
+    /// ```
+    pub fn device(&self) -> Device{
+        Device::Cpu
+    }
+
+    /// Moves tensor to device
+    ///
+    /// Returns a clone for now, as tensor data always stays on cpu
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::fill(1.0, &[2, 2]);
+    ///
+    /// let b: Tensor<f32> = a.to_device(Device::Gpu);
+    ///
+    /// assert_eq!(b.get_data(), a.get_data());
+    /// ```
+    pub fn to_device(&self, _device: Device) -> Tensor<f32>{
+        self.clone()
+    }
+}
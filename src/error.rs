@@ -0,0 +1,30 @@
+use std::fmt;
+
+/// Reason why tensor operation failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TensorError{
+    /// shapes of tensors do not fit each other
+    ShapeMismatch{ expected: Vec<u32>, got: Vec<u32> },
+    /// number of elements does not fit shape
+    DataLengthMismatch{ expected: usize, got: usize },
+    /// operation needs square matrix
+    NotSquare{ shape: Vec<u32> },
+    /// operation needs different number of dimensions
+    RankMismatch{ expected: usize, got: usize },
+    /// position is outside of tensor
+    IndexOutOfBounds{ index: Vec<u32>, shape: Vec<u32> },
+}
+
+impl fmt::Display for TensorError{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result{
+        match self{
+            TensorError::ShapeMismatch{ expected, got } => write!(f, "shape mismatch: expected {:?}, got {:?}", expected, got),
+            TensorError::DataLengthMismatch{ expected, got } => write!(f, "data length mismatch: expected {} elements, got {}", expected, got),
+            TensorError::NotSquare{ shape } => write!(f, "expected square matrix, got shape {:?}", shape),
+            TensorError::RankMismatch{ expected, got } => write!(f, "rank mismatch: expected {} dimensions, got {}", expected, got),
+            TensorError::IndexOutOfBounds{ index, shape } => write!(f, "index {:?} out of bounds for shape {:?}", index, shape),
+        }
+    }
+}
+
+impl std::error::Error for TensorError{}
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{Error, ErrorKind, Result};
+use std::path::Path;
+
+use crate::tensor::Tensor;
+
+const CHECKPOINT_MAGIC: &[u8; 4] = b"FLTC";
+
+fn read_u32(bytes: &[u8], offset: &mut usize) -> Result<u32>{
+    if *offset + 4 > bytes.len(){
+        return Err(Error::new(ErrorKind::UnexpectedEof, "checkpoint is truncated"));
+    }
+
+    let value = u32::from_le_bytes([bytes[*offset], bytes[*offset+1], bytes[*offset+2], bytes[*offset+3]]);
+    *offset += 4;
+
+    Ok(value)
+}
+
+/// Saves named tensors into one file
+/// preserving names, shapes and data
+///
+/// # Example
+/// ```
+/// use flashlight_tensor::prelude::*;
+///
+/// let weights: Tensor<f32> = Tensor::fill(0.5, &[2, 2]);
+/// let bias: Tensor<f32> = Tensor::fill(0.1, &[2]);
+///
+/// let path = std::env::temp_dir().join("flashlight_save_tensors_doc.fltc");
+/// save_tensors(&path, &[("weights", &weights), ("bias", &bias)]).unwrap();
+///
+/// let loaded = load_tensors(&path).unwrap();
+///
+/// assert_eq!(loaded["bias"].get_data(), &vec!{0.1, 0.1});
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+pub fn save_tensors<P: AsRef<Path>>(path: P, tensors: &[(&str, &Tensor<f32>)]) -> Result<()>{
+    let mut bytes: Vec<u8> = Vec::new();
+
+    bytes.extend_from_slice(CHECKPOINT_MAGIC);
+    bytes.extend_from_slice(&(tensors.len() as u32).to_le_bytes());
+
+    for (name, tensor) in tensors{
+        bytes.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(name.as_bytes());
+
+        bytes.extend_from_slice(&(tensor.get_shape().len() as u32).to_le_bytes());
+        for dim in tensor.get_shape(){
+            bytes.extend_from_slice(&dim.to_le_bytes());
+        }
+        for value in tensor.get_data(){
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    fs::write(path, bytes)
+}
+
+/// Loads named tensors saved with save_tensors
+/// Error if file can't be read or is not a valid checkpoint
+///
+/// # Example
+/// ```
+/// use flashlight_tensor::prelude::*;
+///
+/// let weights: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+///
+/// let path = std::env::temp_dir().join("flashlight_load_tensors_doc.fltc");
+/// save_tensors(&path, &[("weights", &weights)]).unwrap();
+///
+/// let loaded = load_tensors(&path).unwrap();
+///
+/// assert_eq!(loaded["weights"].get_data(), &vec!{1.0, 2.0, 3.0, 4.0});
+/// assert_eq!(loaded["weights"].get_shape(), &vec!{2, 2});
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+pub fn load_tensors<P: AsRef<Path>>(path: P) -> Result<HashMap<String, Tensor<f32>>>{
+    let bytes = fs::read(path)?;
+
+    if bytes.len() < 4 || &bytes[0..4] != CHECKPOINT_MAGIC{
+        return Err(Error::new(ErrorKind::InvalidData, "not a tensor checkpoint"));
+    }
+
+    let mut offset: usize = 4;
+    let count = read_u32(&bytes, &mut offset)?;
+
+    //counts come from the file, so nothing is reserved before it is validated
+    let mut tensors: HashMap<String, Tensor<f32>> = HashMap::new();
+    for _ in 0..count{
+        let name_len = read_u32(&bytes, &mut offset)? as usize;
+        if name_len > bytes.len() - offset{
+            return Err(Error::new(ErrorKind::UnexpectedEof, "checkpoint is truncated"));
+        }
+        let name = String::from_utf8(bytes[offset..offset+name_len].to_vec())
+            .map_err(|_| Error::new(ErrorKind::InvalidData, "tensor name is not valid utf-8"))?;
+        offset += name_len;
+
+        let rank = read_u32(&bytes, &mut offset)?;
+        let mut shape: Vec<u32> = Vec::new();
+        for _ in 0..rank{
+            shape.push(read_u32(&bytes, &mut offset)?);
+        }
+
+        let numel = shape.iter().try_fold(1u32, |acc, &dim| acc.checked_mul(dim))
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "tensor shape is too large"))? as usize;
+        let data_end = numel.checked_mul(4).and_then(|len| offset.checked_add(len));
+        if data_end.is_none_or(|end| end > bytes.len()){
+            return Err(Error::new(ErrorKind::InvalidData, "tensor data does not fit checkpoint"));
+        }
+
+        let mut data: Vec<f32> = Vec::with_capacity(numel);
+        for _ in 0..numel{
+            data.push(f32::from_bits(read_u32(&bytes, &mut offset)?));
+        }
+
+        let tensor = Tensor::from_data(&data, &shape)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "tensor data does not fit shape"))?;
+        tensors.insert(name, tensor);
+    }
+
+    Ok(tensors)
+}
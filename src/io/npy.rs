@@ -0,0 +1,148 @@
+use std::fs;
+use std::io::{Error, ErrorKind, Result};
+use std::path::Path;
+
+use crate::tensor::Tensor;
+
+const NPY_MAGIC: &[u8; 6] = b"\x93NUMPY";
+
+fn invalid(message: &str) -> Error{
+    Error::new(ErrorKind::InvalidData, message.to_string())
+}
+
+/// value of key in npy header dict, like '<f4' for 'descr'
+fn header_value<'a>(header: &'a str, key: &str) -> Option<&'a str>{
+    let key_pattern = format!("'{}':", key);
+    let start = header.find(&key_pattern)? + key_pattern.len();
+    let rest = header[start..].trim_start();
+
+    let end = if rest.starts_with('('){
+        rest.find(')')? + 1
+    }
+    else{
+        rest.find(',').or_else(|| rest.find('}'))?
+    };
+
+    Some(rest[..end].trim())
+}
+
+impl Tensor<f32>{
+    /// Saves tensor in numpy .npy format (version 1.0, dtype <f4)
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]).unwrap();
+    ///
+    /// let path = std::env::temp_dir().join("flashlight_save_npy_doc.npy");
+    /// a.save_npy(&path).unwrap();
+    ///
+    /// let b: Tensor<f32> = Tensor::load_npy(&path).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), a.get_data());
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn save_npy<P: AsRef<Path>>(&self, path: P) -> Result<()>{
+        let dims: Vec<String> = self.get_shape().iter().map(|dim| dim.to_string()).collect();
+        let mut shape = format!("({}", dims.join(", "));
+        // one element tuple in python needs trailing comma
+        if dims.len() == 1{
+            shape.push(',');
+        }
+        shape.push(')');
+
+        let mut header = format!("{{'descr': '<f4', 'fortran_order': False, 'shape': {}, }}", shape);
+        // magic, version and header length take 10 bytes, header ends with \n
+        let padding = (64 - (10 + header.len() + 1) % 64) % 64;
+        header.push_str(&" ".repeat(padding));
+        header.push('\n');
+
+        let mut bytes: Vec<u8> = Vec::with_capacity(10 + header.len() + self.get_data().len() * 4);
+        bytes.extend_from_slice(NPY_MAGIC);
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        for value in self.get_data(){
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        fs::write(path, bytes)
+    }
+
+    /// Loads tensor from numpy .npy file with dtype <f4 in c order
+    /// Error if file can't be read, is not npy or has other dtype
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0], &[3]).unwrap();
+    ///
+    /// let path = std::env::temp_dir().join("flashlight_load_npy_doc.npy");
+    /// a.save_npy(&path).unwrap();
+    ///
+    /// let b: Tensor<f32> = Tensor::load_npy(&path).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{1.0, 2.0, 3.0});
+    /// assert_eq!(b.get_shape(), &vec!{3});
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn load_npy<P: AsRef<Path>>(path: P) -> Result<Tensor<f32>>{
+        let bytes = fs::read(path)?;
+
+        if bytes.len() < 10 || &bytes[0..6] != NPY_MAGIC{
+            return Err(invalid("not a npy file"));
+        }
+
+        let (header_start, header_len) = match bytes[6]{
+            1 => (10, u16::from_le_bytes([bytes[8], bytes[9]]) as usize),
+            2 | 3 => {
+                if bytes.len() < 12{
+                    return Err(invalid("npy header is truncated"));
+                }
+                (12, u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize)
+            },
+            _ => return Err(invalid("unsupported npy version")),
+        };
+        if bytes.len() < header_start + header_len{
+            return Err(invalid("npy header is truncated"));
+        }
+
+        let header = std::str::from_utf8(&bytes[header_start..header_start+header_len])
+            .map_err(|_| invalid("npy header is not valid utf-8"))?;
+
+        let descr = header_value(header, "descr").ok_or_else(|| invalid("npy header has no descr"))?;
+        if descr != "'<f4'"{
+            return Err(invalid("npy dtype is not <f4"));
+        }
+        let fortran_order = header_value(header, "fortran_order").ok_or_else(|| invalid("npy header has no fortran_order"))?;
+        if fortran_order != "False"{
+            return Err(invalid("fortran order npy is not supported"));
+        }
+        let shape_text = header_value(header, "shape").ok_or_else(|| invalid("npy header has no shape"))?;
+
+        let mut shape: Vec<u32> = Vec::new();
+        for dim in shape_text.trim_start_matches('(').trim_end_matches(')').split(','){
+            let dim = dim.trim();
+            if dim.is_empty(){
+                continue;
+            }
+            shape.push(dim.parse().map_err(|_| invalid("npy shape is not valid"))?);
+        }
+
+        let data_bytes = &bytes[header_start+header_len..];
+        let numel = shape.iter().try_fold(1u32, |acc, &dim| acc.checked_mul(dim))
+            .ok_or_else(|| invalid("npy shape is too large"))? as usize;
+        if numel.checked_mul(4) != Some(data_bytes.len()){
+            return Err(invalid("npy data does not fit shape"));
+        }
+
+        let mut data: Vec<f32> = Vec::with_capacity(numel);
+        for i in 0..numel{
+            data.push(f32::from_le_bytes([data_bytes[i*4], data_bytes[i*4+1], data_bytes[i*4+2], data_bytes[i*4+3]]));
+        }
+
+        Tensor::from_data(&data, &shape).ok_or_else(|| invalid("npy data does not fit shape"))
+    }
+}
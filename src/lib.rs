@@ -3,6 +3,9 @@
 pub mod tensor;
 pub mod cpu;
 pub mod wgpu;
+pub mod device;
+pub mod io;
+pub mod error;
 pub mod prelude;
 
 #[cfg(test)]
@@ -69,6 +72,67 @@ mod get_tests{
         assert_eq!(tensor.get_data(), &expected_data);
         assert_eq!(tensor.get_shape(), &sizes);
     }
+    #[test]
+    fn to_device_cpu_clone(){
+        let data: Vec<f32> = vec!{1.0, -2.0, 3.5, 4.0, 0.0, 6.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[3, 2]).unwrap();
+
+        let result = tensor.to_device(Device::Cpu);
+
+        assert_eq!(result.get_data(), tensor.get_data());
+        assert_eq!(result.get_shape(), tensor.get_shape());
+        assert_eq!(result.device(), Device::Cpu);
+    }
+    #[test]
+    fn numel(){
+        let tensor: Tensor<f32> = Tensor::new(&[2, 3, 4]);
+
+        assert_eq!(tensor.numel(), 24);
+        assert!(!tensor.is_empty());
+        assert!(Tensor::<f32>::new(&[3, 0, 2]).is_empty());
+    }
+    #[test]
+    fn rank(){
+        let tensor: Tensor<f32> = Tensor::new(&[2, 2, 2]);
+
+        assert_eq!(tensor.rank(), 3);
+    }
+    #[test]
+    fn matrix_predicates(){
+        let square: Tensor<f32> = Tensor::new(&[3, 3]);
+        let rectangle: Tensor<f32> = Tensor::new(&[2, 3]);
+        let cube: Tensor<f32> = Tensor::new(&[2, 2, 2]);
+
+        assert!(square.is_matrix() && square.is_square());
+        assert!(rectangle.is_matrix() && !rectangle.is_square());
+        assert!(!cube.is_matrix() && !cube.is_square());
+    }
+    #[test]
+    fn map_3d(){
+        let data: Vec<f32> = (0..12).map(|i| i as f32 - 6.0).collect();
+        let mut tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3, 2]).unwrap();
+
+        let result = tensor.map(|x| x * x);
+
+        assert_eq!(result.get_shape(), &vec!{2, 3, 2});
+        for (squared, x) in result.get_data().iter().zip(&data){
+            assert_eq!(*squared, x * x);
+        }
+
+        tensor.map_inplace(|x| *x = x.max(0.0));
+        assert_eq!(tensor.get_data(), tensor.relu().get_data());
+        assert_eq!(tensor.get_shape(), &vec!{2, 3, 2});
+    }
+    #[test]
+    fn display(){
+        let matrix: Tensor<f32> = Tensor::from_data(&[1.0, 2.5, -3.0, 4.0], &[2, 2]).unwrap();
+        let vector: Tensor<u32> = Tensor::from_data(&[1, 2, 3], &[3]).unwrap();
+        let scalar: Tensor<f32> = Tensor::from_data(&[7.5], &[1]).unwrap();
+
+        assert_eq!(format!("{}", matrix), "[[1, 2.5], [-3, 4]]");
+        assert_eq!(format!("{}", vector), "[1, 2, 3]");
+        assert_eq!(format!("{}", scalar), "7.5");
+    }
 }
 
 
@@ -195,6 +259,148 @@ mod iterative_operation_tests{
         
         assert_eq!(result_tensor.get_data(), &expected);
     }
+    #[test]
+    fn assert_approx_eq_equal(){
+        let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0], &[3]).unwrap();
+        let b: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0 + 1e-7], &[3]).unwrap();
+
+        a.assert_approx_eq(&b, 1e-5);
+    }
+    #[test]
+    #[should_panic(expected = "Tensors differ at index 3 [1, 1]: left=4 right=4.5")]
+    fn assert_approx_eq_different(){
+        let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+        let b: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.5], &[2, 2]).unwrap();
+
+        a.assert_approx_eq(&b, 1e-5);
+    }
+    #[test]
+    fn renorm_rows(){
+        let data: Vec<f32> = vec!{6.0, 8.0, 0.6, 0.8};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 2]).unwrap();
+
+        let result = tensor.renorm(2.0, 0, 5.0).unwrap();
+
+        let expected: Tensor<f32> = Tensor::from_data(&[3.0, 4.0, 0.6, 0.8], &[2, 2]).unwrap();
+        result.assert_approx_eq(&expected, 1e-5);
+    }
+    #[test]
+    fn renorm_columns(){
+        let data: Vec<f32> = vec!{1.0, 3.0, 1.0, 4.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 2]).unwrap();
+
+        let result = tensor.renorm(1.0, 1, 3.5).unwrap();
+
+        let expected: Tensor<f32> = Tensor::from_data(&[1.0, 1.5, 1.0, 2.0], &[2, 2]).unwrap();
+        result.assert_approx_eq(&expected, 1e-5);
+    }
+    #[test]
+    fn zip3_map_fma(){
+        let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+        let b: Tensor<f32> = Tensor::from_data(&[0.5, -1.0, 2.0, 0.0], &[2, 2]).unwrap();
+        let c: Tensor<f32> = Tensor::from_data(&[1.0, 1.0, -2.0, 3.0], &[2, 2]).unwrap();
+
+        let result = a.zip3_map(&b, &c, |x, y, z| x.mul_add(y, z)).unwrap();
+        let expected = a.fma(&b, &c).unwrap();
+
+        assert_eq!(result.get_data(), expected.get_data());
+        assert_eq!(result.get_shape(), expected.get_shape());
+    }
+    #[test]
+    fn zip3_map_mismatch(){
+        let a: Tensor<f32> = Tensor::fill(1.0, &[2, 2]);
+        let b: Tensor<f32> = Tensor::fill(1.0, &[4]);
+
+        assert!(a.zip3_map(&a, &b, |x, y, z| x + y + z).is_none());
+    }
+    #[test]
+    fn argwhere_negatives(){
+        let data: Vec<f32> = vec!{1.0, -2.0, 3.0, 0.0, -5.0, 6.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3]).unwrap();
+
+        let result = tensor.argwhere(|x| x < 0.0);
+
+        assert_eq!(result, vec!{(vec!{0, 1}, -2.0), (vec!{1, 1}, -5.0)});
+        assert!(tensor.argwhere(|x| x > 100.0).is_empty());
+    }
+    #[test]
+    fn clip_norm(){
+        //norm = 10
+        let big: Tensor<f32> = Tensor::from_data(&[6.0, 0.0, 8.0, 0.0], &[2, 2]).unwrap();
+        //norm = 2
+        let small: Tensor<f32> = Tensor::from_data(&[1.2, 1.6], &[2]).unwrap();
+
+        let clipped = big.clip_norm(5.0);
+        let unchanged = small.clip_norm(5.0);
+
+        let clipped_norm: f32 = clipped.get_data().iter().map(|x| x*x).sum::<f32>().sqrt();
+
+        assert!((clipped_norm - 5.0).abs() < 1e-5);
+        assert_eq!(clipped.get_shape(), &vec!{2, 2});
+        assert_eq!(unchanged.get_data(), small.get_data());
+    }
+    #[test]
+    fn normalize_rows(){
+        let data: Vec<f32> = vec!{1.0, 2.0, 2.0, 0.0, 0.0, 0.0, -5.0, 0.0, 12.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[3, 3]).unwrap();
+
+        let result = tensor.normalize_rows();
+
+        assert_eq!(result.get_shape(), &vec!{3, 3});
+        for row in [0, 2]{
+            let norm: f32 = result.get_data()[row*3..row*3+3].iter().map(|x| x*x).sum::<f32>().sqrt();
+            assert!((norm - 1.0).abs() < 1e-6);
+        }
+        assert_eq!(result.get_data()[3..6], [0.0, 0.0, 0.0]);
+    }
+    #[test]
+    fn clamp_min_relu(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[-3.0, -0.5, 0.0, 0.5, 7.0, -1.0], &[2, 3]).unwrap();
+
+        let result = tensor.clamp_min(0.0);
+
+        assert_eq!(result.get_data(), tensor.relu().get_data());
+        assert_eq!(result.get_shape(), tensor.get_shape());
+    }
+    #[test]
+    fn clamp_max_mut(){
+        let mut tensor: Tensor<i32> = Tensor::from_data(&[-3, 5, 10], &[3]).unwrap();
+
+        tensor.clamp_max_mut(4);
+
+        assert_eq!(tensor.get_data(), &vec!{-3, 4, 4});
+    }
+    #[test]
+    fn checked_product_overflow(){
+        let fits: Tensor<i64> = Tensor::from_data(&[1 << 20, 1 << 20, -(1 << 20)], &[3]).unwrap();
+        let overflows: Tensor<i64> = Tensor::from_data(&[1 << 32, 1 << 31, 3], &[3]).unwrap();
+
+        assert_eq!(fits.checked_product_all(), Some(-(1 << 60)));
+        assert_eq!(overflows.checked_product_all(), None);
+    }
+    #[test]
+    fn nan_to_num(){
+        let data: Vec<f32> = vec!{f32::NAN, -2.5, f32::INFINITY, f32::NEG_INFINITY};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 2]).unwrap();
+
+        let result = tensor.nan_to_num(0.0, 1e6, -1e6);
+
+        assert_eq!(result.get_data(), &vec!{0.0, -2.5, 1e6, -1e6});
+        assert_eq!(result.get_shape(), &vec!{2, 2});
+    }
+    #[test]
+    fn clamp_counted(){
+        let data: Vec<f32> = vec!{-5.0, -1.0, 0.0, 1.0, 2.0, 6.0, 0.3, -0.3};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 4]).unwrap();
+
+        let (result, count) = tensor.clamp_counted(-1.0, 1.0);
+
+        let out_of_range = data.iter().filter(|x| **x < -1.0 || **x > 1.0).count();
+
+        assert_eq!(count, out_of_range);
+        assert_eq!(result.get_data(), &vec!{-1.0, -1.0, 0.0, 1.0, 1.0, 1.0, 0.3, -0.3});
+        assert_eq!(result.get_shape(), &vec!{2, 4});
+    }
 }
 
 #[cfg(test)]
@@ -274,6 +480,189 @@ mod matrix_tests{
         assert_eq!(result.get_data(), &expected_data);
         assert_eq!(result.get_shape(), &expected_sizes);
     }
+    #[test]
+    fn filter_rows(){
+        let data: Vec<f32> = vec!{1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[4, 2]).unwrap();
+        let keep: Tensor<bool> = Tensor::from_data(&[false, true, false, true], &[4]).unwrap();
+
+        let result = tensor.filter_rows(&keep).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{3.0, 4.0, 7.0, 8.0});
+        assert_eq!(result.get_shape(), &vec!{2, 2});
+    }
+    #[test]
+    fn filter_rows_mismatch(){
+        let tensor: Tensor<f32> = Tensor::fill(1.0, &[4, 2]);
+        let keep: Tensor<bool> = Tensor::fill(true, &[3]);
+
+        assert!(tensor.filter_rows(&keep).is_none());
+    }
+    #[test]
+    fn toeplitz(){
+        let col: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[4]).unwrap();
+        let row: Tensor<f32> = Tensor::from_data(&[1.0, 5.0, 6.0], &[3]).unwrap();
+
+        let result: Tensor<f32> = Tensor::toeplitz(&col, &row).unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{4, 3});
+        for i in 1..4{
+            for j in 1..3{
+                assert_eq!(result.value(&[i, j]), result.value(&[i-1, j-1]));
+            }
+        }
+        assert_eq!(result.matrix_col(0).unwrap().get_data(), col.get_data());
+        assert_eq!(result.matrix_row(0).unwrap().get_data(), row.get_data());
+    }
+    #[test]
+    fn toeplitz_corner_mismatch(){
+        let col: Tensor<f32> = Tensor::from_data(&[1.0, 2.0], &[2]).unwrap();
+        let row: Tensor<f32> = Tensor::from_data(&[0.0, 3.0], &[2]).unwrap();
+
+        assert!(Tensor::toeplitz(&col, &row).is_none());
+    }
+    #[test]
+    fn vandermonde(){
+        let x: Tensor<f32> = Tensor::from_data(&[-1.0, 0.5, 2.0, 3.0], &[4]).unwrap();
+
+        let result: Tensor<f32> = Tensor::vandermonde(&x, 3).unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{4, 4});
+        for j in 0..4{
+            let expected: Vec<f32> = x.get_data().iter().map(|v| v.powi(j as i32)).collect();
+            assert_eq!(result.matrix_col(j).unwrap().get_data(), &expected);
+        }
+    }
+    #[test]
+    fn polyfit_quadratic(){
+        let x_data: Vec<f32> = vec!{-2.0, -1.0, 0.0, 0.5, 1.0, 2.0, 3.0};
+        let y_data: Vec<f32> = x_data.iter().map(|x| 2.0 - 3.0*x + 0.5*x*x).collect();
+
+        let x: Tensor<f32> = Tensor::from_data(&x_data, &[7]).unwrap();
+        let y: Tensor<f32> = Tensor::from_data(&y_data, &[7]).unwrap();
+
+        let result: Tensor<f32> = Tensor::polyfit(&x, &y, 2).unwrap();
+
+        let expected: Tensor<f32> = Tensor::from_data(&[2.0, -3.0, 0.5], &[3]).unwrap();
+        result.assert_approx_eq(&expected, 1e-3);
+    }
+    #[test]
+    fn polyfit_mismatch(){
+        let x: Tensor<f32> = Tensor::fill(1.0, &[3]);
+        let y: Tensor<f32> = Tensor::fill(1.0, &[4]);
+
+        assert!(Tensor::polyfit(&x, &y, 1).is_none());
+    }
+    #[test]
+    fn batched_dot(){
+        let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, -1.0, 0.5, 2.0], &[2, 3]).unwrap();
+        let b: Tensor<f32> = Tensor::from_data(&[4.0, 5.0, 6.0, 2.0, 2.0, -3.0], &[2, 3]).unwrap();
+
+        let result = a.batched_dot(&b).unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{2});
+        for row in 0..2{
+            let mut a_row = a.matrix_row(row).unwrap();
+            let mut b_row = b.matrix_row(row).unwrap();
+            a_row.set_shape(&[3]);
+            b_row.set_shape(&[3]);
+
+            let expected = a_row.dot_product(&b_row).unwrap();
+            assert_eq!(result.get_data()[row as usize], expected);
+        }
+        assert!(a.batched_dot(&b.matrix_transpose().unwrap()).is_none());
+    }
+    #[test]
+    fn pairwise_cosine(){
+        let data: Vec<f32> = vec!{1.0, 2.0, 3.0, -2.0, 0.5, 4.0, 0.0, 1.0, -1.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[3, 3]).unwrap();
+
+        let result = tensor.pairwise_cosine().unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{3, 3});
+        for i in 0..3{
+            assert!((result.value(&[i, i]).unwrap() - 1.0).abs() < 1e-5);
+            for j in 0..3{
+                assert!((result.value(&[i, j]).unwrap() - result.value(&[j, i]).unwrap()).abs() < 1e-6);
+            }
+        }
+    }
+    #[test]
+    fn cross_features(){
+        let a: Tensor<f32> = Tensor::from_data(&[1.0, -2.0, 3.0], &[1, 3]).unwrap();
+        let b: Tensor<f32> = Tensor::from_data(&[2.0, 0.5], &[1, 2]).unwrap();
+
+        let result = a.cross_features(&b).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{2.0, 0.5, -4.0, -1.0, 6.0, 1.5});
+        assert_eq!(result.get_shape(), &vec!{1, 6});
+        assert!(a.cross_features(&Tensor::fill(1.0, &[2, 2])).is_none());
+    }
+    #[test]
+    fn batched_outer(){
+        let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, -1.0, 0.0, 2.0], &[2, 3]).unwrap();
+        let b: Tensor<f32> = Tensor::from_data(&[4.0, 5.0, 0.5, 1.5], &[2, 2]).unwrap();
+
+        let result = a.batched_outer(&b).unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{2, 3, 2});
+
+        let a_row: Tensor<f32> = Tensor::from_data(&a.get_data()[3..6], &[3]).unwrap();
+        let b_row: Tensor<f32> = Tensor::from_data(&b.get_data()[2..4], &[2]).unwrap();
+        let expected = a_row.outer_apply(&b_row, |x, y| x * y).unwrap();
+
+        assert_eq!(result.get_data()[6..12], expected.get_data()[..]);
+    }
+    #[test]
+    fn sparse_coo_round_trip(){
+        let data: Vec<f32> = vec!{0.0, 0.0, 1.5, 0.0, 0.0, 0.0, -2.0, 0.0, 0.0, 0.0, 0.0, 7.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3, 2]).unwrap();
+
+        let (coords, values) = tensor.to_sparse_coo();
+        let result: Tensor<f32> = Tensor::from_sparse_coo(&coords, &values, tensor.get_shape()).unwrap();
+
+        assert_eq!(values.len(), 3);
+        assert_eq!(result.get_data(), tensor.get_data());
+        assert_eq!(result.get_shape(), tensor.get_shape());
+        assert!(Tensor::from_sparse_coo(&[vec!{2, 0, 0}], &[1.0], &[2, 3, 2]).is_none());
+    }
+    #[test]
+    fn matmul_bt(){
+        let a: Tensor<f32> = Tensor::from_data(&[1.0, -2.0, 0.5, 3.0, 4.0, 1.0], &[2, 3]).unwrap();
+        let b: Tensor<f32> = Tensor::from_data(&[2.0, 1.0, 0.0, -1.0, 3.0, 2.0, 0.5, 0.5, 4.0, 1.0, 0.0, -3.0], &[4, 3]).unwrap();
+
+        let expected = a.matrix_mul(&b.matrix_transpose().unwrap()).unwrap();
+        let result = a.matmul_bt(&b).unwrap();
+
+        assert_eq!(result.get_shape(), expected.get_shape());
+        result.assert_approx_eq(&expected, 1e-6);
+        assert!(a.matmul_bt(&a.matrix_transpose().unwrap()).is_none());
+    }
+    #[test]
+    fn determinant(){
+        let data: Vec<f32> = vec!{0.0, 2.0, 1.0, 3.0, -1.0, 2.0, 1.0, 1.0, 1.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[3, 3]).unwrap();
+
+        //0*(-1-2) - 2*(3-2) + 1*(3+1) = 2
+        assert!((tensor.determinant().unwrap() - 2.0).abs() < 1e-5);
+
+        let single: Tensor<f32> = Tensor::from_data(&[-3.5], &[1, 1]).unwrap();
+        assert_eq!(single.determinant(), Some(-3.5));
+        assert!(Tensor::<f32>::fill(1.0, &[2, 3]).determinant().is_none());
+    }
+    #[test]
+    fn matvec(){
+        let a: Tensor<f32> = Tensor::from_data(&[1.0, -2.0, 0.5, 3.0, 4.0, 1.0, 0.0, 2.0, -1.0, 1.5, 2.5, -3.0], &[3, 4]).unwrap();
+        let v: Tensor<f32> = Tensor::from_data(&[2.0, 1.0, -1.0, 0.5], &[4]).unwrap();
+
+        let column = v.reshape(&[4, 1]).unwrap();
+        let expected = a.matrix_mul(&column).unwrap().flatten();
+        let result = a.matvec(&v).unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{3});
+        result.assert_approx_eq(&expected, 1e-6);
+        assert!(a.matvec(&column).is_none());
+    }
 }
 
 #[cfg(test)]
@@ -339,6 +728,106 @@ mod additional_tests{
 
         assert_eq!(result, expected);
     }
+    #[test]
+    fn strides(){
+        let tensor: Tensor<f32> = Tensor::new(&[2, 3, 4]);
+
+        assert_eq!(tensor.strides(), vec!{12, 4, 1});
+        assert!(tensor.is_contiguous());
+    }
+    #[test]
+    fn strides_vector(){
+        let tensor: Tensor<f32> = Tensor::new(&[5]);
+
+        assert_eq!(tensor.strides(), vec!{1});
+    }
+    #[test]
+    fn resize_grow(){
+        let mut tensor: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+
+        assert!(tensor.resize(&[3, 3], 9.0));
+
+        assert_eq!(tensor.get_data(), &vec!{1.0, 2.0, 3.0, 4.0, 9.0, 9.0, 9.0, 9.0, 9.0});
+        assert_eq!(tensor.get_shape(), &vec!{3, 3});
+    }
+    #[test]
+    fn resize_shrink(){
+        let mut tensor: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+
+        assert!(tensor.resize(&[3], 9.0));
+
+        assert_eq!(tensor.get_data(), &vec!{1.0, 2.0, 3.0});
+        assert_eq!(tensor.get_shape(), &vec!{3});
+    }
+    #[test]
+    fn indexed_iter(){
+        let data: Vec<f32> = vec!{1.0, 2.0, 3.0, 4.0, 5.0, 6.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3]).unwrap();
+
+        let expected_positions: Vec<Vec<u32>> = vec!{
+            vec!{0, 0}, vec!{0, 1}, vec!{0, 2},
+            vec!{1, 0}, vec!{1, 1}, vec!{1, 2},
+        };
+
+        let result: Vec<(Vec<u32>, &f32)> = tensor.indexed_iter().collect();
+
+        assert_eq!(result.len(), 6);
+        for i in 0..result.len(){
+            assert_eq!(result[i].0, expected_positions[i]);
+            assert_eq!(result[i].1, &data[i]);
+        }
+    }
+    #[test]
+    fn winsorize_pulls_in_extremes(){
+        let mut data: Vec<f32> = Vec::with_capacity(11);
+        for i in 0..11{
+            data.push(i as f32);
+        }
+        data[0] = -1000.0;
+        data[10] = 1000.0;
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[11]).unwrap();
+
+        let result = tensor.winsorize(10.0, 90.0);
+
+        assert_eq!(result.get_data()[0], 1.0);
+        assert_eq!(result.get_data()[10], 9.0);
+        assert_eq!(&result.get_data()[1..10], &data[1..10]);
+    }
+    #[test]
+    fn iter_mut_mutations_persist(){
+        let mut a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]).unwrap();
+
+        for x in a.iter_mut(){
+            *x *= 10.0;
+        }
+        for x in &mut a{
+            *x -= 1.0;
+        }
+
+        assert_eq!(a.get_data(), &vec!{9.0, 19.0, 29.0, 39.0, 49.0, 59.0});
+        assert_eq!(a.get_shape(), &vec!{2, 3});
+
+        let mut visited: Vec<f32> = Vec::new();
+        for x in &a{
+            visited.push(*x);
+        }
+        assert_eq!(&visited, a.get_data());
+        assert_eq!(a.into_iter().collect::<Vec<f32>>(), visited);
+    }
+    #[test]
+    fn constructors_empty_shape_is_scalar(){
+        let zeros: Tensor<f32> = Tensor::zeros(&[]);
+        let ones: Tensor<f32> = Tensor::ones(&[]);
+        let filled: Tensor<f32> = Tensor::fill(3.0, &[]);
+        let full: Tensor<f32> = Tensor::full(3.0, &[]);
+
+        assert_eq!(zeros.get_data(), &vec!{0.0});
+        assert_eq!(ones.get_data(), &vec!{1.0});
+        assert_eq!(zeros.numel(), zeros.get_data().len());
+        assert_eq!(filled.get_data().len(), zeros.get_data().len());
+        assert_eq!(full.get_data(), filled.get_data());
+        assert!(zeros.is_contiguous());
+    }
 }
 
 #[cfg(test)]
@@ -996,3 +1485,1241 @@ mod wgpu_tests{
         assert_eq!(gpu_output.get_shape(), cpu_output.get_shape());
     } 
 }
+
+#[cfg(test)]
+mod machine_learning_tests{
+    use prelude::*;
+    use super::*;
+
+    #[test]
+    fn attention_shape(){
+        let q: Tensor<f32> = Tensor::fill(0.5, &[2, 4]);
+        let k: Tensor<f32> = Tensor::fill(0.5, &[3, 4]);
+        let v: Tensor<f32> = Tensor::fill(1.0, &[3, 5]);
+
+        let result = Tensor::scaled_dot_product_attention(&q, &k, &v, None).unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{2, 5});
+    }
+    #[test]
+    fn attention_mask(){
+        let q: Tensor<f32> = Tensor::fill(1.0, &[1, 2]);
+        let k: Tensor<f32> = Tensor::fill(1.0, &[3, 2]);
+        let v: Tensor<f32> = Tensor::from_data(&[1.0, 3.0, 1000.0], &[3, 1]).unwrap();
+        let mask: Tensor<bool> = Tensor::from_data(&[false, false, true], &[1, 3]).unwrap();
+
+        let result = Tensor::scaled_dot_product_attention(&q, &k, &v, Some(&mask)).unwrap();
+
+        let epsilon = 1e-5;
+        assert!((result.get_data()[0] - 2.0).abs() < epsilon);
+    }
+    #[test]
+    fn positional_encoding(){
+        let seq_len = 5;
+        let d_model = 6;
+
+        let result: Tensor<f32> = Tensor::sinusoidal_positional_encoding(seq_len, d_model).unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{5, 6});
+
+        let epsilon = 1e-5;
+        for (pos, i) in [(0, 0), (1, 1), (3, 2), (4, 1)]{
+            let angle = pos as f32 / 10000f32.powf((2 * i) as f32 / d_model as f32);
+            assert!((result.value(&[pos, 2*i]).unwrap() - angle.sin()).abs() < epsilon);
+            assert!((result.value(&[pos, 2*i+1]).unwrap() - angle.cos()).abs() < epsilon);
+        }
+    }
+    #[test]
+    fn positional_encoding_odd(){
+        let result = Tensor::sinusoidal_positional_encoding(4, 5);
+
+        assert!(result.is_none());
+    }
+    #[test]
+    fn avg_pool2d(){
+        let data: Vec<f32> = (1..=16).map(|x| x as f32).collect();
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[4, 4]).unwrap();
+
+        let result = tensor.avg_pool2d(2, 2, 2, false).unwrap();
+
+        //[1, 2, 5, 6], [3, 4, 7, 8], [9, 10, 13, 14], [11, 12, 15, 16]
+        assert_eq!(result.get_data(), &vec!{3.5, 5.5, 11.5, 13.5});
+        assert_eq!(result.get_shape(), &vec!{2, 2});
+    }
+    #[test]
+    fn avg_pool2d_padding(){
+        let data: Vec<f32> = (1..=16).map(|x| x as f32).collect();
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[4, 4]).unwrap();
+
+        let excluded = tensor.avg_pool2d(3, 3, 2, false).unwrap();
+        let included = tensor.avg_pool2d(3, 3, 2, true).unwrap();
+
+        //windows on the edges only have 6 and 4 elements
+        assert_eq!(excluded.get_shape(), &vec!{2, 2});
+        assert_eq!(excluded.get_data(), &vec!{6.0, 7.5, 12.0, 13.5});
+        assert_eq!(included.get_data(), &vec!{6.0, 45.0/9.0, 72.0/9.0, 54.0/9.0});
+    }
+    #[test]
+    fn avg_pool2d_stride_larger_than_pool(){
+        let data: Vec<f32> = (1..=25).map(|x| x as f32).collect();
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[5, 5]).unwrap();
+
+        //windows start at 0 and 3, no window starts past the edge
+        let excluded = tensor.avg_pool2d(1, 1, 3, false).unwrap();
+        let included = tensor.avg_pool2d(1, 1, 3, true).unwrap();
+
+        assert_eq!(excluded.get_shape(), &vec!{2, 2});
+        assert_eq!(excluded.get_data(), &vec!{1.0, 4.0, 16.0, 19.0});
+        assert_eq!(included.get_data(), &vec!{1.0, 4.0, 16.0, 19.0});
+
+        //partial tail window starting at 3 still counts
+        let data: Vec<f32> = (1..=16).map(|x| x as f32).collect();
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[4, 4]).unwrap();
+
+        let excluded = tensor.avg_pool2d(2, 2, 3, false).unwrap();
+        let included = tensor.avg_pool2d(2, 2, 3, true).unwrap();
+
+        assert_eq!(excluded.get_shape(), &vec!{2, 2});
+        assert_eq!(excluded.get_data(), &vec!{3.5, 6.0, 13.5, 16.0});
+        assert_eq!(included.get_data(), &vec!{3.5, 3.0, 6.75, 4.0});
+    }
+    #[test]
+    fn avg_pool2d_stride_one(){
+        let data: Vec<f32> = (1..=16).map(|x| x as f32).collect();
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[4, 4]).unwrap();
+
+        let pool2 = tensor.avg_pool2d(2, 2, 1, false).unwrap();
+        let pool3 = tensor.avg_pool2d(3, 3, 1, true).unwrap();
+
+        //only full windows, no 1-wide windows on the edges
+        assert_eq!(pool2.get_shape(), &vec!{3, 3});
+        assert_eq!(pool2.get_data(), &vec!{3.5, 4.5, 5.5, 7.5, 8.5, 9.5, 11.5, 12.5, 13.5});
+        assert_eq!(pool3.get_shape(), &vec!{2, 2});
+        assert_eq!(pool3.get_data(), &vec!{6.0, 7.0, 10.0, 11.0});
+    }
+    #[test]
+    fn softmax_temperature_flattens(){
+        let logits: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 4.0, 0.0, 0.0, 3.0], &[2, 3]).unwrap();
+
+        let sharp = logits.softmax_axis_t(1, 0.5).unwrap();
+        let flat = logits.softmax_axis_t(1, 4.0).unwrap();
+
+        for row in 0..2{
+            let sharp_row = &sharp.get_data()[row*3..row*3 + 3];
+            let flat_row = &flat.get_data()[row*3..row*3 + 3];
+
+            assert!((sharp_row.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+            assert!((flat_row.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+            assert!(flat_row[2] < sharp_row[2]);
+            assert!(flat_row.iter().cloned().fold(0.0, f32::max) - flat_row.iter().cloned().fold(1.0, f32::min)
+                < sharp_row.iter().cloned().fold(0.0, f32::max) - sharp_row.iter().cloned().fold(1.0, f32::min));
+        }
+        assert!(logits.softmax_axis_t(1, 0.0).is_none());
+        assert!(logits.softmax_axis_t(1, -1.0).is_none());
+    }
+    #[test]
+    fn softmax_hand_computed(){
+        let logits: Tensor<f32> = Tensor::from_data(&[0.0, 1.0_f32.ln(), 3.0_f32.ln(), 0.0], &[2, 2]).unwrap();
+
+        //row 0: [1, 1] / 2, row 1: [3, 1] / 4
+        let expected: Tensor<f32> = Tensor::from_data(&[0.5, 0.5, 0.75, 0.25], &[2, 2]).unwrap();
+
+        logits.softmax(1).unwrap().assert_approx_eq(&expected, 1e-6);
+
+        let log_expected: Tensor<f32> = Tensor::from_data(&[0.5_f32.ln(), 0.5_f32.ln(), 0.75_f32.ln(), 0.25_f32.ln()], &[2, 2]).unwrap();
+        logits.log_softmax(1).unwrap().assert_approx_eq(&log_expected, 1e-6);
+
+        //column softmax: [1, 3] / 4 and [1, 1] / 2
+        let column_expected: Tensor<f32> = Tensor::from_data(&[0.25, 0.5, 0.75, 0.5], &[2, 2]).unwrap();
+        logits.softmax(0).unwrap().assert_approx_eq(&column_expected, 1e-6);
+
+        assert!(logits.softmax(2).is_none());
+        assert!(logits.log_softmax(2).is_none());
+    }
+    #[test]
+    fn relu_all_negative(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[-1.0, -0.5, -100.0, -1e-6], &[4]).unwrap();
+
+        let result = tensor.relu();
+
+        assert_eq!(result.get_data(), &vec!{0.0; 4});
+        assert_eq!(result.get_shape(), &vec!{4});
+    }
+    #[test]
+    fn hard_tanh_regions(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[-10.0, -2.0, -1.5, 0.0, 1.0, 1.9, 2.0, 7.5], &[2, 4]).unwrap();
+
+        let result = tensor.hard_tanh(-2.0, 2.0);
+        let derivative = tensor.hard_tanh_derivative(-2.0, 2.0);
+
+        assert_eq!(result.get_data(), &vec!{-2.0, -2.0, -1.5, 0.0, 1.0, 1.9, 2.0, 2.0});
+        assert_eq!(derivative.get_data(), &vec!{0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 0.0, 0.0});
+        assert_eq!(result.get_shape(), &vec!{2, 4});
+    }
+    #[test]
+    fn silu_reference_and_finite_difference(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[-3.0, -1.0, 0.5, 2.0], &[4]).unwrap();
+
+        //x / (1 + e^-x)
+        let expected: Tensor<f32> = Tensor::from_data(&[-0.142278, -0.268941, 0.311230, 1.761594], &[4]).unwrap();
+        tensor.silu().assert_approx_eq(&expected, 1e-5);
+
+        let h: f32 = 1e-2;
+        let plus = tensor.add(h).silu();
+        let minus = tensor.add(-h).silu();
+        let derivative = tensor.silu_der();
+        for i in 0..4{
+            let numeric = (plus.get_data()[i] - minus.get_data()[i]) / (2.0 * h);
+            assert!((numeric - derivative.get_data()[i]).abs() < 1e-3);
+        }
+    }
+    #[test]
+    fn mish_reference_values(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[-2.0, 0.0, 2.0], &[3]).unwrap();
+
+        let expected: Tensor<f32> = Tensor::from_data(&[-0.252501, 0.0, 1.943959], &[3]).unwrap();
+        tensor.mish().assert_approx_eq(&expected, 1e-5);
+
+        let large: Tensor<f32> = Tensor::from_data(&[100.0, 1e30, -1e30], &[3]).unwrap();
+        let result = large.mish();
+        assert!(result.get_data().iter().all(|x| x.is_finite()));
+        assert_eq!(result.get_data()[0], 100.0);
+        assert_eq!(result.get_data()[1], 1e30);
+    }
+    #[test]
+    fn log_sigmoid_stable(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[-100.0, -3.0, -0.5, 0.0, 1.5, 4.0], &[6]).unwrap();
+
+        let result = tensor.log_sigmoid();
+
+        assert!(result.get_data()[0].is_finite());
+        assert!((result.get_data()[0] + 100.0).abs() < 1e-4);
+
+        let naive = tensor.sigmoid().map(|x| x.ln());
+        for i in 1..6{
+            assert!((result.get_data()[i] - naive.get_data()[i]).abs() < 1e-5);
+        }
+    }
+    #[test]
+    fn scale_temperature_inplace_halves(){
+        let mut logits: Tensor<f32> = Tensor::from_data(&[2.0, -4.0, 1.0, 0.0], &[2, 2]).unwrap();
+
+        assert!(logits.scale_temperature_inplace(2.0));
+        assert_eq!(logits.get_data(), &vec!{1.0, -2.0, 0.5, 0.0});
+        assert_eq!(logits.get_shape(), &vec!{2, 2});
+
+        assert!(!logits.scale_temperature_inplace(0.0));
+        assert!(!logits.scale_temperature_inplace(-1.0));
+        assert_eq!(logits.get_data(), &vec!{1.0, -2.0, 0.5, 0.0});
+    }
+}
+
+#[cfg(test)]
+mod vector_tests{
+    use prelude::*;
+    use super::*;
+
+    #[test]
+    fn outer_apply_difference(){
+        let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0], &[3]).unwrap();
+        let b: Tensor<f32> = Tensor::from_data(&[1.0, 5.0], &[2]).unwrap();
+
+        let expected_data: Vec<f32> = vec!{0.0, -4.0, 1.0, -3.0, 2.0, -2.0};
+        let expected_sizes: Vec<u32> = vec!{3, 2};
+
+        let result = a.outer_apply(&b, |x, y| x - y).unwrap();
+
+        assert_eq!(result.get_data(), &expected_data);
+        assert_eq!(result.get_shape(), &expected_sizes);
+    }
+    #[test]
+    fn outer_apply_not_vector(){
+        let a: Tensor<f32> = Tensor::fill(1.0, &[2, 2]);
+        let b: Tensor<f32> = Tensor::fill(1.0, &[2]);
+
+        assert!(a.outer_apply(&b, |x, y| x - y).is_none());
+    }
+    #[test]
+    fn sum_outer_transpose(){
+        let a: Tensor<f32> = Tensor::from_data(&[1.0, -2.0, 0.5], &[3]).unwrap();
+        let b: Tensor<f32> = Tensor::from_data(&[10.0, 20.0], &[2]).unwrap();
+
+        let ab = a.sum_outer(&b).unwrap();
+        let ba = b.sum_outer(&a).unwrap();
+
+        assert_eq!(ab.get_data(), &vec!{11.0, 21.0, 8.0, 18.0, 10.5, 20.5});
+        assert_eq!(ab.get_shape(), &vec!{3, 2});
+        assert_eq!(ab.get_data(), ba.matrix_transpose().unwrap().get_data());
+    }
+}
+
+#[cfg(test)]
+mod broadcasting_tests{
+    use prelude::*;
+    use super::*;
+
+    #[test]
+    fn fma_precision(){
+        //a * a = 1 + 2^-11 + 2^-24, naive multiplication rounds off the 2^-24
+        let x: f32 = 1.0 + 2f32.powi(-12);
+
+        let a: Tensor<f32> = Tensor::fill(x, &[2, 2]);
+        let c: Tensor<f32> = Tensor::fill(-(1.0 + 2f32.powi(-11)), &[1, 1]);
+
+        let fused = a.fma(&a, &c).unwrap();
+        let naive = a.tens_broadcast_mul(&a).unwrap().tens_broadcast_add(&Tensor::fill(-(1.0 + 2f32.powi(-11)), &[2, 2])).unwrap();
+
+        assert_eq!(fused.get_shape(), &vec!{2, 2});
+        assert_eq!(fused.get_data(), &vec!{2f32.powi(-24); 4});
+        assert_eq!(naive.get_data(), &vec!{0.0; 4});
+    }
+    #[test]
+    fn fma_incompatible(){
+        let a: Tensor<f32> = Tensor::fill(1.0, &[2, 3]);
+        let b: Tensor<f32> = Tensor::fill(1.0, &[2, 2]);
+
+        assert!(a.fma(&b, &a).is_none());
+    }
+    #[test]
+    fn fma_features_onto_batch(){
+        let x: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0], &[4, 3]).unwrap();
+        let gamma: Tensor<f32> = Tensor::from_data(&[1.0, 0.5, -1.0], &[3]).unwrap();
+        let beta: Tensor<f32> = Tensor::from_data(&[0.0, 1.0, 2.0], &[3]).unwrap();
+
+        //gamma * x + beta, for each row of batch
+        let result = gamma.fma(&x, &beta).unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{4, 3});
+        assert_eq!(result.get_data(), &vec!{1.0, 2.0, -1.0, 4.0, 3.5, -4.0, 7.0, 5.0, -7.0, 10.0, 6.5, -10.0});
+        assert_eq!(x.fma(&gamma, &beta).unwrap().get_data(), result.get_data());
+    }
+    #[test]
+    fn pow_tensor_scalar_exponent(){
+        let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0], &[3]).unwrap();
+        let b: Tensor<f32> = Tensor::from_data(&[2.0], &[1]).unwrap();
+
+        let result = a.pow_tensor(&b).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{1.0, 4.0, 9.0});
+        assert_eq!(result.get_shape(), &vec!{3});
+    }
+    #[test]
+    fn pow_tensor_same_shape(){
+        let a: Tensor<f32> = Tensor::from_data(&[2.0, 3.0, 4.0, 9.0], &[2, 2]).unwrap();
+        let b: Tensor<f32> = Tensor::from_data(&[3.0, 0.0, -1.0, 0.5], &[2, 2]).unwrap();
+
+        let result = a.pow_tensor(&b).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{8.0, 1.0, 0.25, 3.0});
+        assert_eq!(result.get_shape(), &vec!{2, 2});
+    }
+    #[test]
+    fn add_channel_bias(){
+        let data: Vec<f32> = vec!{1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 2, 2]).unwrap();
+        let bias: Tensor<f32> = Tensor::from_data(&[10.0, -1.0], &[2]).unwrap();
+
+        let result = tensor.add_channel_bias(&bias).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{11.0, 12.0, 13.0, 14.0, 4.0, 5.0, 6.0, 7.0});
+        assert_eq!(result.get_shape(), &vec!{2, 2, 2});
+        assert!(tensor.add_channel_bias(&Tensor::fill(1.0, &[3])).is_none());
+    }
+    #[test]
+    fn affine_matches_mul_then_add(){
+        let data: Vec<f32> = vec!{1.0, -2.0, 3.0, 0.5, 4.0, -1.5};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3]).unwrap();
+        let scale: Tensor<f32> = Tensor::from_data(&[2.0, 0.5, -1.0], &[3]).unwrap();
+        let shift: Tensor<f32> = Tensor::from_data(&[0.1, 0.2, 0.3], &[3]).unwrap();
+
+        let mut scale_row = scale.clone();
+        scale_row.set_shape(&[1, 3]);
+        let mut shift_row = shift.clone();
+        shift_row.set_shape(&[1, 3]);
+        let expected = tensor.tens_broadcast_mul(&scale_row).unwrap().tens_broadcast_add(&shift_row).unwrap();
+
+        let result = tensor.affine(&scale, &shift).unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{2, 3});
+        result.assert_approx_eq(&expected, 1e-6);
+        assert!(tensor.affine(&Tensor::fill(1.0, &[2]), &shift).is_none());
+    }
+    #[test]
+    fn broadcast_to_length(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[1, 4]).unwrap();
+
+        let result = tensor.broadcast_to_length(0, 5).unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{5, 4});
+        for row in 0..5{
+            assert_eq!(&result.get_data()[row*4..row*4 + 4], tensor.get_data().as_slice());
+        }
+        assert!(tensor.broadcast_to_length(1, 8).is_none());
+        assert!(tensor.broadcast_to_length(2, 8).is_none());
+    }
+}
+
+#[cfg(test)]
+mod reduction_tests{
+    use prelude::*;
+    use super::*;
+
+    #[test]
+    fn logsumexp_stable(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[1000.0, 999.0, 998.0], &[3]).unwrap();
+
+        let result = tensor.logsumexp_axis(0).unwrap();
+
+        let expected = 1000.0 + (1.0 + (-1f32).exp() + (-2f32).exp()).ln();
+
+        assert_eq!(result.get_shape(), &vec!{1});
+        assert!(result.get_data()[0].is_finite());
+        assert!((result.get_data()[0] - expected).abs() < 1e-3);
+    }
+    #[test]
+    fn logsumexp_naive(){
+        let data: Vec<f32> = vec!{0.1, -0.5, 1.2, 0.3, 2.0, -1.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3]).unwrap();
+
+        let result = tensor.logsumexp_axis(0).unwrap();
+
+        let expected: Vec<f32> = (0..3)
+            .map(|j| (data[j].exp() + data[3+j].exp()).ln())
+            .collect();
+
+        result.assert_approx_eq(&Tensor::from_data(&expected, &[3]).unwrap(), 1e-5);
+    }
+    #[test]
+    fn trace_axes_matrix(){
+        let data: Vec<f32> = vec!{1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[3, 3]).unwrap();
+
+        let result = tensor.trace_axes(0, 1).unwrap();
+
+        let trace: f32 = (0..3).map(|i| tensor.value(&[i, i]).unwrap()).sum();
+
+        assert_eq!(result.get_data(), &vec!{trace});
+        assert_eq!(result.get_shape(), &vec!{1});
+    }
+    #[test]
+    fn trace_axes_partial(){
+        let data: Vec<f32> = (0..12).map(|x| x as f32).collect();
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3, 2]).unwrap();
+
+        let result = tensor.trace_axes(2, 0).unwrap();
+
+        //result[j] = t[0, j, 0] + t[1, j, 1]
+        let expected: Vec<f32> = vec!{0.0 + 7.0, 2.0 + 9.0, 4.0 + 11.0};
+
+        assert_eq!(result.get_data(), &expected);
+        assert_eq!(result.get_shape(), &vec!{3});
+        assert!(tensor.trace_axes(0, 1).is_none());
+    }
+    #[test]
+    fn prod_axis_matrix(){
+        let data: Vec<f32> = vec!{1.0, 2.0, 3.0, 4.0, 5.0, 6.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3]).unwrap();
+
+        let rows = tensor.prod_axis(0).unwrap();
+        let cols = tensor.prod_axis(1).unwrap();
+
+        assert_eq!(rows.get_data(), &vec!{4.0, 10.0, 18.0});
+        assert_eq!(rows.get_shape(), &vec!{3});
+        assert_eq!(cols.get_data(), &vec!{6.0, 120.0});
+        assert_eq!(cols.get_shape(), &vec!{2});
+    }
+    #[test]
+    fn prod_axis_middle(){
+        let data: Vec<i32> = (1..=12).collect();
+        let tensor: Tensor<i32> = Tensor::from_data(&data, &[2, 3, 2]).unwrap();
+
+        let result = tensor.prod_axis(1).unwrap();
+
+        //1*3*5, 2*4*6, 7*9*11, 8*10*12
+        assert_eq!(result.get_data(), &vec!{15, 48, 693, 960});
+        assert_eq!(result.get_shape(), &vec!{2, 2});
+        assert!(tensor.prod_axis(3).is_none());
+    }
+    #[test]
+    fn mode_majority(){
+        let tensor: Tensor<u32> = Tensor::from_data(&[3, 1, 3, 2, 3, 1, 3], &[7]).unwrap();
+
+        assert_eq!(tensor.mode(), Some(3));
+    }
+    #[test]
+    fn mode_axis_votes(){
+        //3 models voting on 4 samples
+        let votes: Vec<u32> = vec!{
+            0, 1, 2, 2,
+            0, 1, 1, 0,
+            1, 1, 2, 3,
+        };
+        let tensor: Tensor<u32> = Tensor::from_data(&votes, &[3, 4]).unwrap();
+
+        let result = tensor.mode_axis(0).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{0, 1, 2, 2});
+        assert_eq!(result.get_shape(), &vec!{4});
+    }
+    #[test]
+    fn unique_counts(){
+        let data: Vec<i32> = vec!{5, 5, 1, 7, 1, 5, 9, 7};
+        let tensor: Tensor<i32> = Tensor::from_data(&data, &[2, 4]).unwrap();
+
+        let unique = tensor.unique();
+        let (values, counts) = tensor.unique_counts();
+
+        assert_eq!(unique.get_data(), &vec!{5, 1, 7, 9});
+        assert_eq!(unique.get_shape(), &vec!{4});
+        assert_eq!(values.get_data(), unique.get_data());
+        assert_eq!(counts.get_data(), &vec!{3, 2, 2, 1});
+        assert_eq!(counts.get_data().iter().sum::<u32>() as usize, tensor.get_data().len());
+    }
+    #[test]
+    fn bincount(){
+        let tensor: Tensor<u32> = Tensor::from_data(&[0, 1, 1, 3], &[4]).unwrap();
+
+        let result = tensor.bincount(5).unwrap();
+        let short = tensor.bincount(2).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{1, 2, 0, 1, 0});
+        assert_eq!(result.get_shape(), &vec!{5});
+        assert_eq!(short.get_data(), &vec!{1, 2, 0, 1});
+
+        let overflowing: Tensor<u32> = Tensor::from_data(&[0, u32::MAX], &[2]).unwrap();
+        assert!(overflowing.bincount(0).is_none());
+    }
+    #[test]
+    fn sum_axis_sequential_total(){
+        let data: Vec<f32> = (0..24).map(|i| i as f32 * 0.25 - 2.0).collect();
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3, 4]).unwrap();
+        let total: f32 = data.iter().sum();
+
+        let middle = tensor.sum_axis(1).unwrap();
+        assert_eq!(middle.get_shape(), &vec!{2, 4});
+
+        let result = tensor.sum_axis(2).unwrap().sum_axis(1).unwrap().sum_axis(0).unwrap();
+        assert_eq!(result.get_shape(), &vec!{1});
+        assert!((result.get_data()[0] - total).abs() < 1e-4);
+
+        let mean = tensor.mean_axis(1).unwrap();
+        for i in 0..mean.get_data().len(){
+            assert!((mean.get_data()[i] * 3.0 - middle.get_data()[i]).abs() < 1e-5);
+        }
+        assert!(tensor.sum_axis(3).is_none());
+        assert!(tensor.mean_axis(3).is_none());
+    }
+    #[test]
+    fn global_reductions(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[2.0, -1.0, 4.0, 0.5, 3.0, -6.0], &[2, 3]).unwrap();
+
+        assert_eq!(tensor.sum(), 2.5);
+        assert_eq!(tensor.product(), 72.0);
+        assert!((tensor.mean() - 2.5 / 6.0).abs() < 1e-6);
+        assert_eq!(tensor.max(), Some(4.0));
+        assert_eq!(tensor.min(), Some(-6.0));
+
+        let single: Tensor<f32> = Tensor::from_data(&[3.0], &[1]).unwrap();
+        assert_eq!(single.max(), Some(3.0));
+        assert_eq!(single.min(), Some(3.0));
+
+        let empty: Tensor<f32> = Tensor::new(&[0]);
+        assert_eq!(empty.max(), None);
+        assert_eq!(empty.min(), None);
+    }
+    #[test]
+    fn argmax_argmin(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[3.0, 1.0, 8.0, 8.0, 0.5, 2.0, -1.0, 4.0, 4.0, -1.0, 0.0, 2.0], &[2, 3, 2]).unwrap();
+
+        assert_eq!(tensor.argmax(), Some(2));
+        assert_eq!(tensor.argmin(), Some(6));
+        assert_eq!(tensor.argmax_coords(), Some(vec!{0, 1, 0}));
+
+        let axis = tensor.argmax_axis(1).unwrap();
+        assert_eq!(axis.get_shape(), &vec!{2, 2});
+        assert_eq!(axis.get_data(), &vec!{1, 1, 1, 0});
+
+        let empty: Tensor<f32> = Tensor::new(&[0]);
+        assert_eq!(empty.argmax(), None);
+        assert_eq!(empty.argmax_coords(), None);
+    }
+    #[test]
+    fn masked_mean_padded_sequences(){
+        //[batch 2, time 3, features 2], second sequence has one valid step
+        let data: Vec<f32> = vec!{1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 100.0, 100.0, 100.0, 100.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3, 2]).unwrap();
+        let mask: Tensor<bool> = Tensor::from_data(&[true, true, true, true, true, true, true, true, false, false, false, false], &[2, 3, 2]).unwrap();
+
+        let result = tensor.masked_mean(&mask, 1).unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{2, 2});
+        assert_eq!(result.get_data(), &vec!{3.0, 4.0, 7.0, 8.0});
+        assert!(tensor.masked_mean(&Tensor::fill(true, &[2, 3]), 1).is_none());
+    }
+    #[test]
+    fn weighted_mean_uniform(){
+        let data: Vec<f32> = vec!{1.0, -2.0, 3.5, 0.5, 4.0, 6.0, -1.0, 2.0, 0.0, 3.0, 5.0, -4.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[3, 4]).unwrap();
+
+        let row_weights: Tensor<f32> = Tensor::fill(2.0, &[4]);
+        let column_weights: Tensor<f32> = Tensor::fill(0.5, &[3, 1]);
+
+        tensor.weighted_mean(&row_weights, 1).unwrap().assert_approx_eq(&tensor.mean_axis(1).unwrap(), 1e-6);
+        tensor.weighted_mean(&column_weights, 0).unwrap().assert_approx_eq(&tensor.mean_axis(0).unwrap(), 1e-6);
+
+        let zero: Tensor<f32> = Tensor::fill(0.0, &[4]);
+        assert_eq!(tensor.weighted_mean(&zero, 1).unwrap().get_data(), &vec!{0.0; 3});
+        assert!(tensor.weighted_mean(&Tensor::fill(1.0, &[3]), 1).is_none());
+    }
+}
+
+#[cfg(test)]
+mod calculus_tests{
+    use prelude::*;
+    use super::*;
+
+    #[test]
+    fn diff_vector(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[1.0, 3.0, 6.0, 10.0], &[4]).unwrap();
+
+        let result = tensor.diff(0).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{2.0, 3.0, 4.0});
+        assert_eq!(result.get_shape(), &vec!{3});
+    }
+    #[test]
+    fn diff_matrix_columns(){
+        let data: Vec<f32> = vec!{1.0, 2.0, 4.0, 0.0, 5.0, 5.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3]).unwrap();
+
+        let result = tensor.diff(1).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{1.0, 2.0, 5.0, 0.0});
+        assert_eq!(result.get_shape(), &vec!{2, 2});
+    }
+    #[test]
+    fn gradient_linear_ramp(){
+        let data: Vec<f32> = (0..6).map(|x| 3.0 * x as f32 * 0.5).collect();
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[6]).unwrap();
+
+        let result = tensor.gradient_axis(0, 0.5).unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{6});
+        result.assert_approx_eq(&Tensor::fill(3.0, &[6]), 1e-5);
+    }
+    #[test]
+    fn gradient_matrix_rows(){
+        let data: Vec<f32> = vec!{0.0, 1.0, 2.0, 10.0, 21.0, 32.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3]).unwrap();
+
+        let result = tensor.gradient_axis(0, 2.0).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{5.0, 10.0, 15.0, 5.0, 10.0, 15.0});
+    }
+    #[test]
+    fn interp_midpoints(){
+        let xp: Tensor<f32> = Tensor::from_data(&[0.0, 1.0, 3.0, 4.0], &[4]).unwrap();
+        let fp: Tensor<f32> = Tensor::from_data(&[1.0, 3.0, -1.0, 0.0], &[4]).unwrap();
+        let x: Tensor<f32> = Tensor::from_data(&[0.5, 2.0, 3.5, 1.0], &[2, 2]).unwrap();
+
+        let result: Tensor<f32> = Tensor::interp(&x, &xp, &fp).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{2.0, 1.0, -0.5, 3.0});
+        assert_eq!(result.get_shape(), &vec!{2, 2});
+    }
+    #[test]
+    fn interp_clamp(){
+        let xp: Tensor<f32> = Tensor::from_data(&[0.0, 1.0], &[2]).unwrap();
+        let fp: Tensor<f32> = Tensor::from_data(&[5.0, 7.0], &[2]).unwrap();
+        let x: Tensor<f32> = Tensor::from_data(&[-10.0, 10.0], &[2]).unwrap();
+
+        let result: Tensor<f32> = Tensor::interp(&x, &xp, &fp).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{5.0, 7.0});
+    }
+    #[test]
+    fn trapz_constant(){
+        let tensor: Tensor<f32> = Tensor::fill(3.0, &[2, 5]);
+
+        let result = tensor.trapz(1, 0.25).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{0.25 * 4.0 * 3.0; 2});
+        assert_eq!(result.get_shape(), &vec!{2});
+    }
+    #[test]
+    fn trapz_linear(){
+        let data: Vec<f32> = vec!{0.0, 0.0, 1.0, 2.0, 2.0, 4.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[3, 2]).unwrap();
+
+        let result = tensor.trapz(0, 1.0).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{2.0, 4.0});
+    }
+    #[test]
+    fn erf_known_values(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[0.0, 1.0, 2.0, -2.0], &[4]).unwrap();
+
+        let expected: Tensor<f32> = Tensor::from_data(&[0.0, 0.8427008, 0.9953223, -0.9953223], &[4]).unwrap();
+        tensor.erf().assert_approx_eq(&expected, 1e-6);
+
+        let expected_c: Tensor<f32> = Tensor::from_data(&[1.0, 0.1572992, 0.0046777, 1.9953223], &[4]).unwrap();
+        tensor.erfc().assert_approx_eq(&expected_c, 1e-6);
+    }
+    #[test]
+    fn normal_cdf_and_pdf(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[2.5], &[1]).unwrap();
+        assert!((tensor.normal_cdf(2.5, 0.7).unwrap().get_data()[0] - 0.5).abs() < 1e-6);
+
+        let grid: Vec<f32> = (0..=2000).map(|i| -10.0 + i as f32 * 0.01).collect();
+        let x: Tensor<f32> = Tensor::from_data(&grid, &[2001]).unwrap();
+        let pdf = x.normal_pdf(0.5, 1.5).unwrap();
+        let area = pdf.trapz(0, 0.01).unwrap();
+
+        assert!((area.get_data()[0] - 1.0).abs() < 1e-4);
+        assert!(x.normal_pdf(0.0, 0.0).is_none());
+        assert!(x.normal_cdf(0.0, -1.0).is_none());
+    }
+}
+
+#[cfg(test)]
+mod manipulation_tests{
+    use prelude::*;
+    use super::*;
+
+    #[test]
+    fn roll_multi(){
+        let data: Vec<f32> = (1..=9).map(|x| x as f32).collect();
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[3, 3]).unwrap();
+
+        let result = tensor.roll_multi(&[1, 2], &[0, 1]).unwrap();
+
+        let expected: Vec<f32> = vec!{
+            8.0, 9.0, 7.0,
+            2.0, 3.0, 1.0,
+            5.0, 6.0, 4.0,
+        };
+
+        assert_eq!(result.get_data(), &expected);
+        assert_eq!(result.get_shape(), &vec!{3, 3});
+    }
+    #[test]
+    fn roll_multi_invalid(){
+        let tensor: Tensor<f32> = Tensor::new(&[3, 3]);
+
+        assert!(tensor.roll_multi(&[1], &[0, 1]).is_none());
+        assert!(tensor.roll_multi(&[1, 1], &[0, 0]).is_none());
+        assert!(tensor.roll_multi(&[1], &[2]).is_none());
+    }
+    #[test]
+    fn pad_modes(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[4]).unwrap();
+
+        let constant = tensor.pad_mode(&[2], &[0], PadMode::Constant(-1.0)).unwrap();
+        let reflect = tensor.pad_mode(&[2], &[0], PadMode::Reflect).unwrap();
+        let replicate = tensor.pad_mode(&[2], &[0], PadMode::Replicate).unwrap();
+
+        assert_eq!(constant.get_data(), &vec!{-1.0, -1.0, 1.0, 2.0, 3.0, 4.0});
+        assert_eq!(reflect.get_data(), &vec!{3.0, 2.0, 1.0, 2.0, 3.0, 4.0});
+        assert_eq!(replicate.get_data(), &vec!{1.0, 1.0, 1.0, 2.0, 3.0, 4.0});
+        assert_eq!(reflect.get_shape(), &vec!{6});
+    }
+    #[test]
+    fn pad_mode_matrix(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+
+        let result = tensor.pad_mode(&[0, 1], &[1, 0], PadMode::Replicate).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{1.0, 1.0, 2.0, 3.0, 3.0, 4.0, 3.0, 3.0, 4.0});
+        assert_eq!(result.get_shape(), &vec!{3, 3});
+        assert!(tensor.pad_mode(&[2, 0], &[0, 0], PadMode::Reflect).is_none());
+    }
+    #[test]
+    fn masked_select_threshold(){
+        let data: Vec<f32> = vec!{0.5, 7.0, 3.0, 12.0, -1.0, 9.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3]).unwrap();
+
+        let mask_data: Vec<bool> = data.iter().map(|x| *x > 5.0).collect();
+        let mask: Tensor<bool> = Tensor::from_data(&mask_data, &[2, 3]).unwrap();
+
+        let result = tensor.masked_select(&mask).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{7.0, 12.0, 9.0});
+        assert_eq!(result.get_shape(), &vec!{3});
+        assert!(tensor.masked_select(&Tensor::fill(true, &[3, 2])).is_none());
+    }
+    #[test]
+    fn permute_round_trip(){
+        let data: Vec<f32> = (0..24).map(|i| i as f32 * 0.5).collect();
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3, 4]).unwrap();
+
+        let permuted = tensor.permute(&[2, 0, 1]).unwrap();
+        let result = permuted.permute(&[1, 2, 0]).unwrap();
+
+        assert_eq!(permuted.get_shape(), &vec!{4, 2, 3});
+        assert_eq!(result.get_shape(), tensor.get_shape());
+        assert_eq!(result.get_data(), tensor.get_data());
+        assert!(tensor.permute(&[0, 0, 1]).is_none());
+        assert!(tensor.permute(&[0, 1]).is_none());
+    }
+    #[test]
+    fn pad_sequences_batch(){
+        let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0], &[2]).unwrap();
+        let b: Tensor<f32> = Tensor::from_data(&[3.0, 4.0, 5.0, 6.0], &[4]).unwrap();
+        let c: Tensor<f32> = Tensor::from_data(&[7.0], &[1]).unwrap();
+
+        let (batch, mask) = pad_sequences(&[&a, &b, &c], -1.0).unwrap();
+
+        assert_eq!(batch.get_shape(), &vec!{3, 4});
+        assert_eq!(batch.get_data(), &vec!{1.0, 2.0, -1.0, -1.0, 3.0, 4.0, 5.0, 6.0, 7.0, -1.0, -1.0, -1.0});
+        assert_eq!(mask.get_shape(), &vec!{3, 4});
+        assert_eq!(mask.get_data(), &vec!{false, false, true, true, false, false, false, false, false, true, true, true});
+
+        let d: Tensor<f32> = Tensor::from_data(&[1.0, 2.0], &[1, 2]).unwrap();
+        assert!(pad_sequences(&[&a, &d], 0.0).is_none());
+    }
+    #[test]
+    fn merge_and_split_leading(){
+        let data: Vec<f32> = (0..24).map(|i| i as f32).collect();
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3, 4]).unwrap();
+
+        let (merged, dims) = tensor.merge_leading(2).unwrap();
+        assert_eq!(merged.get_shape(), &vec!{6, 4});
+        assert_eq!(merged.get_data(), tensor.get_data());
+
+        let restored = merged.split_leading(&dims).unwrap();
+        assert_eq!(restored.get_shape(), tensor.get_shape());
+        assert_eq!(restored.get_data(), tensor.get_data());
+
+        assert!(tensor.merge_leading(4).is_none());
+        assert!(merged.split_leading(&[4, 2]).is_none());
+    }
+    #[test]
+    fn concat_inner_axis(){
+        let a: Tensor<f32> = Tensor::from_data(&(0..12).map(|i| i as f32).collect::<Vec<f32>>(), &[2, 3, 2]).unwrap();
+        let b: Tensor<f32> = Tensor::from_data(&[100.0, 101.0, 102.0, 103.0], &[2, 1, 2]).unwrap();
+
+        let result = Tensor::concat(&[a.clone(), b.clone()], 1).unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{2, 4, 2});
+        assert_eq!(result.get_data(), &vec!{0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 100.0, 101.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 102.0, 103.0});
+        assert!(Tensor::concat(&[a.clone(), b.clone()], 0).is_none());
+        assert!(Tensor::<f32>::concat(&[], 0).is_none());
+    }
+    #[test]
+    fn stack_and_unstack(){
+        let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]).unwrap();
+        let b: Tensor<f32> = a.map(|x| x * 10.0);
+        let c: Tensor<f32> = a.map(|x| -x);
+        let originals = vec!{a, b, c};
+
+        let stacked = Tensor::stack(&originals, 0).unwrap();
+        assert_eq!(stacked.get_shape(), &vec!{3, 2, 3});
+        for (chunk, original) in stacked.get_data().chunks(6).zip(&originals){
+            assert_eq!(chunk, original.get_data().as_slice());
+        }
+
+        let last = Tensor::stack(&originals, 2).unwrap();
+        assert_eq!(last.get_shape(), &vec!{2, 3, 3});
+        let unstacked = last.permute(&[2, 0, 1]).unwrap();
+        for (chunk, original) in unstacked.get_data().chunks(6).zip(&originals){
+            assert_eq!(chunk, original.get_data().as_slice());
+        }
+
+        assert!(Tensor::stack(&originals, 3).is_none());
+        assert!(Tensor::stack(&[originals[0].clone(), Tensor::fill(1.0, &[3, 2])], 0).is_none());
+    }
+    #[test]
+    fn slice_inner_axis(){
+        let data: Vec<f32> = (0..24).map(|i| i as f32).collect();
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3, 4]).unwrap();
+
+        let result = tensor.slice(&[(0, 2), (1, 2)]).unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{2, 1, 4});
+        assert_eq!(result.get_data(), &vec!{4.0, 5.0, 6.0, 7.0, 16.0, 17.0, 18.0, 19.0});
+        assert!(tensor.slice(&[(1, 0)]).is_none());
+        assert!(tensor.slice(&[(0, 3)]).is_none());
+        assert!(tensor.slice(&[(0, 1), (0, 1), (0, 1), (0, 1)]).is_none());
+    }
+}
+
+#[cfg(test)]
+mod rolling_tests{
+    use prelude::*;
+    use super::*;
+
+    #[test]
+    fn causal_max(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[2.0, -1.0, 5.0, 3.0, 1.0, 0.0, 4.0], &[7]).unwrap();
+
+        let result = tensor.causal_max(0, 3).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{2.0, 2.0, 5.0, 5.0, 5.0, 3.0, 4.0});
+        assert_eq!(result.get_shape(), &vec!{7});
+    }
+    #[test]
+    fn causal_max_matrix(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[1.0, 5.0, 4.0, 2.0, 3.0, 0.0], &[3, 2]).unwrap();
+
+        let result = tensor.causal_max(0, 2).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{1.0, 5.0, 4.0, 5.0, 4.0, 2.0});
+        assert!(tensor.causal_max(0, 0).is_none());
+    }
+    #[test]
+    fn moving_average(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0], &[5]).unwrap();
+
+        let result = tensor.moving_average(3, 0).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{2.0, 3.0, 4.0});
+        assert_eq!(result.get_shape(), &vec!{3});
+        assert!(tensor.moving_average(6, 0).is_none());
+    }
+    #[test]
+    fn moving_average_matrix(){
+        let data: Vec<f32> = vec!{1.0, 3.0, 5.0, 2.0, 2.0, 8.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3]).unwrap();
+
+        let result = tensor.moving_average(2, 1).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{2.0, 4.0, 2.0, 5.0});
+        assert_eq!(result.get_shape(), &vec!{2, 2});
+    }
+    #[test]
+    fn sliding_windows(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0], &[5]).unwrap();
+
+        let result = tensor.sliding_windows(2, 1).unwrap();
+
+        assert_eq!(result.get_data(), &vec!{1.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0, 5.0});
+        assert_eq!(result.get_shape(), &vec!{4, 2});
+        assert!(tensor.sliding_windows(6, 1).is_none());
+    }
+    #[test]
+    fn rolling_sum_matches_naive(){
+        let data: Vec<f32> = vec!{0.5, -1.0, 2.0, 3.5, 0.0, 4.0, -2.5, 1.0, 1.5, 2.0, -0.5, 3.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 6]).unwrap();
+
+        for window in 1..=6{
+            let result = tensor.rolling_sum(window, 1).unwrap();
+            let output_length = (6 - window + 1) as usize;
+            assert_eq!(result.get_shape(), &vec!{2, output_length as u32});
+
+            for row in 0..2{
+                for i in 0..output_length{
+                    let naive: f32 = data[row*6 + i..row*6 + i + window as usize].iter().sum();
+                    assert!((result.get_data()[row*output_length + i] - naive).abs() < 1e-5);
+                }
+            }
+        }
+
+        let columns = tensor.rolling_sum(2, 0).unwrap();
+        assert_eq!(columns.get_data(), &vec!{-2.0, 0.0, 3.5, 5.5, -0.5, 7.0});
+        assert!(tensor.rolling_sum(7, 1).is_none());
+        assert!(tensor.rolling_sum(0, 1).is_none());
+    }
+}
+
+#[cfg(test)]
+mod probability_tests{
+    use prelude::*;
+    use super::*;
+
+    #[test]
+    fn project_simplex(){
+        let data: Vec<f32> = vec!{0.2, 0.9, -0.3, 3.0, 2.5, 1.0, 0.1, 0.1, 0.1};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[3, 3]).unwrap();
+
+        let result = tensor.project_simplex(1).unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{3, 3});
+        assert!(result.get_data().iter().all(|x| *x >= 0.0));
+        for row in 0..3{
+            let sum: f32 = result.get_data()[row*3..row*3+3].iter().sum();
+            assert!((sum - 1.0).abs() < 1e-5);
+        }
+        //equal values are projected to uniform distribution
+        result.matrix_row(2).unwrap().assert_approx_eq(&Tensor::fill(1.0/3.0, &[1, 3]), 1e-6);
+    }
+    #[test]
+    fn entropy_uniform_one_hot(){
+        let data: Vec<f32> = vec!{0.25, 0.25, 0.25, 0.25, 0.0, 1.0, 0.0, 0.0};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 4]).unwrap();
+
+        let result = tensor.entropy_axis(1).unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{2});
+        assert!((result.get_data()[0] - 4f32.ln()).abs() < 1e-6);
+        assert_eq!(result.get_data()[1], 0.0);
+    }
+    #[test]
+    fn kl_div_self(){
+        let data: Vec<f32> = vec!{0.1, 0.2, 0.7, 0.0, 0.5, 0.5};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 3]).unwrap();
+
+        let result = tensor.kl_div(&tensor, 1).unwrap();
+
+        assert_eq!(result.get_shape(), &vec!{2});
+        result.assert_approx_eq(&Tensor::fill(0.0, &[2]), 1e-6);
+    }
+    #[test]
+    fn kl_div_zero_q(){
+        let p: Tensor<f32> = Tensor::from_data(&[0.5, 0.5], &[2]).unwrap();
+        let q: Tensor<f32> = Tensor::from_data(&[1.0, 0.0], &[2]).unwrap();
+
+        let result = p.kl_div(&q, 0).unwrap();
+
+        assert!(result.get_data()[0].is_finite());
+        assert!(result.get_data()[0] > 0.0);
+    }
+    #[test]
+    fn jensen_shannon(){
+        let p: Tensor<f32> = Tensor::from_data(&[0.1, 0.6, 0.3, 0.5, 0.5, 0.0], &[2, 3]).unwrap();
+        let q: Tensor<f32> = Tensor::from_data(&[0.3, 0.3, 0.4, 0.2, 0.2, 0.6], &[2, 3]).unwrap();
+
+        let same = p.jensen_shannon(&p, 1).unwrap();
+        let pq = p.jensen_shannon(&q, 1).unwrap();
+        let qp = q.jensen_shannon(&p, 1).unwrap();
+
+        same.assert_approx_eq(&Tensor::fill(0.0, &[2]), 1e-6);
+        pq.assert_approx_eq(&qp, 1e-6);
+        assert!(pq.get_data().iter().all(|x| *x > 0.0 && *x <= 2f32.ln()));
+    }
+    #[test]
+    fn cumulative_distribution_axis(){
+        let data: Vec<f32> = vec!{0.9, 0.1, 0.25, 0.75};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 2]).unwrap();
+
+        //rows are already distributions, so cdf is their running sum
+        let rows = tensor.cumulative_distribution_axis(1).unwrap();
+        rows.assert_approx_eq(&Tensor::from_data(&[0.9, 1.0, 0.25, 1.0], &[2, 2]).unwrap(), 1e-6);
+
+        //columns sum to 1.15 and 0.85, so they are normalized first
+        let columns = tensor.cumulative_distribution_axis(0).unwrap();
+        columns.assert_approx_eq(&Tensor::from_data(&[0.9/1.15, 0.1/0.85, 1.0, 1.0], &[2, 2]).unwrap(), 1e-6);
+
+        let zero_row: Tensor<f32> = Tensor::from_data(&[0.5, 0.5, 0.0, 0.0], &[2, 2]).unwrap();
+        assert!(zero_row.cumulative_distribution_axis(1).is_none());
+        assert!(tensor.cumulative_distribution_axis(2).is_none());
+    }
+    #[test]
+    fn top_p_mask_peaked(){
+        let data: Vec<f32> = vec!{0.01, 0.95, 0.02, 0.02, 0.25, 0.25, 0.3, 0.2};
+        let tensor: Tensor<f32> = Tensor::from_data(&data, &[2, 4]).unwrap();
+
+        let mask = tensor.top_p_mask(1, 0.5).unwrap();
+
+        assert_eq!(mask.get_shape(), &vec!{2, 4});
+        assert_eq!(mask.get_data(), &vec!{false, true, false, false, true, false, true, false});
+
+        let all = tensor.top_p_mask(1, 1.0).unwrap();
+        assert_eq!(&all.get_data()[4..8], &[true, true, true, true]);
+        assert!(tensor.top_p_mask(1, 0.0).is_none());
+    }
+}
+
+#[cfg(test)]
+mod operator_tests{
+    use prelude::*;
+    use super::*;
+
+    #[test]
+    fn operators_matching_shapes(){
+        let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+        let b: Tensor<f32> = Tensor::from_data(&[2.0, 2.0, 4.0, 8.0], &[2, 2]).unwrap();
+
+        assert_eq!((&a + &b).get_data(), &vec!{3.0, 4.0, 7.0, 12.0});
+        assert_eq!((&a - &b).get_data(), &vec!{-1.0, 0.0, -1.0, -4.0});
+        assert_eq!((&a * &b).get_data(), &vec!{2.0, 4.0, 12.0, 32.0});
+        assert_eq!((&a / &b).get_data(), &vec!{0.5, 1.0, 0.75, 0.5});
+        assert_eq!((&a + &b).get_shape(), &vec!{2, 2});
+        assert_eq!(a.try_mul(&b).unwrap().get_data(), (&a * &b).get_data());
+    }
+    #[test]
+    #[should_panic(expected = "left=[2, 2] right=[3]")]
+    fn operators_mismatched_shapes(){
+        let a: Tensor<f32> = Tensor::fill(1.0, &[2, 2]);
+        let b: Tensor<f32> = Tensor::fill(1.0, &[3]);
+
+        let _ = &a + &b;
+    }
+    #[test]
+    fn operators_vector_and_matrix(){
+        let a: Tensor<f32> = Tensor::fill(1.0, &[4]);
+        let b: Tensor<f32> = Tensor::fill(1.0, &[2, 2]);
+
+        assert!(a.try_add(&b).is_none());
+        assert!(a.try_sub(&b).is_none());
+        assert!(a.try_mul(&b).is_none());
+        assert!(a.try_div(&b).is_none());
+        assert_eq!((&a + &a).get_shape(), &vec!{4});
+    }
+}
+
+#[cfg(test)]
+mod io_tests{
+    use prelude::*;
+    use super::*;
+
+    #[test]
+    fn checkpoint_round_trip(){
+        let weights: Tensor<f32> = Tensor::from_data(&[0.5, -1.25, 3.0, 0.0, 2.5, -0.75], &[2, 3]).unwrap();
+        let bias: Tensor<f32> = Tensor::from_data(&[0.1, -0.2], &[2]).unwrap();
+
+        let path = std::env::temp_dir().join("flashlight_checkpoint_round_trip.fltc");
+        save_tensors(&path, &[("weights", &weights), ("bias", &bias)]).unwrap();
+        let loaded = load_tensors(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded["weights"].get_data(), weights.get_data());
+        assert_eq!(loaded["weights"].get_shape(), weights.get_shape());
+        assert_eq!(loaded["bias"].get_data(), bias.get_data());
+        assert_eq!(loaded["bias"].get_shape(), bias.get_shape());
+    }
+    #[test]
+    fn checkpoint_malformed_header(){
+        fn header(words: &[u32]) -> Vec<u8>{
+            let mut bytes: Vec<u8> = b"FLTC".to_vec();
+            for word in words{
+                bytes.extend_from_slice(&word.to_le_bytes());
+            }
+            bytes
+        }
+        //name "w" is stored as length 1 followed by one byte
+        fn named(words: &[u32]) -> Vec<u8>{
+            let mut bytes = header(&[1, 1]);
+            bytes.push(b'w');
+            for word in words{
+                bytes.extend_from_slice(&word.to_le_bytes());
+            }
+            bytes
+        }
+
+        let cases: Vec<Vec<u8>> = vec!{
+            header(&[u32::MAX]),
+            named(&[2, 100000, 100000]),
+            named(&[2, u32::MAX, u32::MAX]),
+            named(&[1, 1 << 30]),
+            named(&[2, 2, 2, 0, 0]),
+        };
+
+        let path = std::env::temp_dir().join("flashlight_checkpoint_malformed.fltc");
+        for bytes in cases{
+            std::fs::write(&path, &bytes).unwrap();
+            assert!(load_tensors(&path).is_err());
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+    #[test]
+    fn npy_round_trip(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[0.5, -1.25, 3.0, 0.0, 2.5, -0.75], &[2, 3]).unwrap();
+
+        let path = std::env::temp_dir().join("flashlight_npy_round_trip.npy");
+        tensor.save_npy(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        let result: Tensor<f32> = Tensor::load_npy(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let header = String::from_utf8_lossy(&bytes[10..128]).to_string();
+        assert!(header.starts_with("{'descr': '<f4', 'fortran_order': False, 'shape': (2, 3), }"));
+        assert_eq!(bytes.len(), 128 + 6 * 4);
+
+        assert_eq!(result.get_data(), tensor.get_data());
+        assert_eq!(result.get_shape(), tensor.get_shape());
+    }
+    #[test]
+    fn npy_overflowing_shape(){
+        let header = "{'descr': '<f4', 'fortran_order': False, 'shape': (100000, 100000), }\n";
+        let mut bytes: Vec<u8> = b"\x93NUMPY\x01\x00".to_vec();
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(&[0; 16]);
+
+        let path = std::env::temp_dir().join("flashlight_npy_overflowing_shape.npy");
+        std::fs::write(&path, &bytes).unwrap();
+        let result = Tensor::load_npy(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(result.err().map(|error| error.kind()), Some(std::io::ErrorKind::InvalidData));
+    }
+}
+
+#[cfg(test)]
+mod random_tests{
+    use prelude::*;
+    use super::*;
+
+    #[test]
+    fn random_seeded(){
+        let a: Tensor<f32> = Tensor::random(&[3, 4], 1234);
+        let b: Tensor<f32> = Tensor::random(&[3, 4], 1234);
+        let c: Tensor<f32> = Tensor::random(&[3, 4], 1235);
+
+        assert_eq!(a.get_data(), b.get_data());
+        assert_ne!(a.get_data(), c.get_data());
+        assert_eq!(a.get_shape(), &vec!{3, 4});
+
+        let d: Tensor<f32> = Tensor::random_range(&[3, 4], 1234, 2.0, 4.0);
+        for i in 0..d.get_data().len(){
+            assert!((d.get_data()[i] - (2.0 + 2.0 * a.get_data()[i])).abs() < 1e-6);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests{
+    use prelude::*;
+    use super::*;
+
+    #[test]
+    fn json_round_trip(){
+        let tensor: Tensor<f32> = Tensor::from_data(&[1.0, -2.5, 3.0, 0.0, 4.25, -6.0], &[2, 3]).unwrap();
+
+        let json = serde_json::to_string(&tensor).unwrap();
+        let result: Tensor<f32> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(result.get_data(), tensor.get_data());
+        assert_eq!(result.get_shape(), tensor.get_shape());
+    }
+    #[test]
+    fn json_mismatched_shape_fails(){
+        assert!(serde_json::from_str::<Tensor<f32>>(r#"{"data":[1.0],"shape":[2,3]}"#).is_err());
+        assert!(serde_json::from_str::<Tensor<f32>>(r#"{"data":[],"shape":[4294967295,4294967295,4294967295]}"#).is_err());
+        assert!(serde_json::from_str::<Tensor<f32>>(r#"{"data":[1.0,2.0],"shape":[2]}"#).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod error_tests{
+    use prelude::*;
+    use super::*;
+
+    #[test]
+    fn error_variants_carry_shapes(){
+        let a: Tensor<f32> = Tensor::fill(1.0, &[2, 3]);
+        let b: Tensor<f32> = Tensor::fill(1.0, &[4, 5]);
+        let cube: Tensor<f32> = Tensor::fill(1.0, &[2, 2, 2]);
+
+        assert_eq!(a.matrix_mul_checked(&b).err(), Some(TensorError::ShapeMismatch{ expected: vec!{3, 5}, got: vec!{4, 5} }));
+        assert_eq!(cube.matrix_mul_checked(&a).err(), Some(TensorError::RankMismatch{ expected: 2, got: 3 }));
+        assert_eq!(a.determinant_checked(), Err(TensorError::NotSquare{ shape: vec!{2, 3} }));
+        assert_eq!(a.value_checked(&[1, 3]), Err(TensorError::IndexOutOfBounds{ index: vec!{1, 3}, shape: vec!{2, 3} }));
+        assert_eq!(a.value_checked(&[1, 1, 1]), Err(TensorError::RankMismatch{ expected: 2, got: 3 }));
+        assert_eq!(a.reshape_checked(&[5]).err(), Some(TensorError::DataLengthMismatch{ expected: 5, got: 6 }));
+
+        assert!(a.matrix_mul(&b).is_none());
+        assert!(a.value(&[1, 1, 1]).is_none());
+        assert_eq!(a.matrix_mul_checked(&b.slice(&[(0, 3)]).unwrap()).unwrap().get_shape(), &vec!{2, 5});
+    }
+    #[test]
+    fn error_display(){
+        let error = TensorError::ShapeMismatch{ expected: vec!{3, 5}, got: vec!{4, 5} };
+
+        assert_eq!(error.to_string(), "shape mismatch: expected [3, 5], got [4, 5]");
+
+        let boxed: Box<dyn std::error::Error> = Box::new(error);
+        assert!(boxed.to_string().contains("[4, 5]"));
+    }
+}
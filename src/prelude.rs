@@ -2,6 +2,8 @@
 
 pub use crate::{
     tensor::*,
+    device::*,
+    error::*,
     cpu::{
         math::{
             functions::*,
@@ -9,20 +11,53 @@ pub use crate::{
             division::*,
             multiplication::*,
             subtraction::*,
+            comparison::*,
+            norms::*,
+            reductions::*,
+            calculus::*,
+            rolling::*,
+            clamping::*,
+            counting::*,
+            probability::*,
+            operators::*,
+            special::*,
         },
         subtypes::{
             matrix::*,
             vector::*,
             helpers::*,
+            sparse::*,
         },
         machine_learning::{
             relu::*,
             sigmoid::*,
+            attention::*,
+            positional_encoding::*,
+            pooling::*,
+            softmax::*,
+            tanh::*,
+            silu::*,
+            mish::*,
         },
         broadcasting::{
             helpers::*,
             operations::*,
-        }
+        },
+        manipulation::{
+            roll::*,
+            padding::*,
+            masking::*,
+            permute::*,
+            concat::*,
+            slice::*,
+        },
+        random::{
+            uniform::*,
+        },
+    },
+    io::{
+        checkpoint::*,
+        npy::*,
     },
     wgpu::*,
 };
@@ -1,12 +1,44 @@
+use crate::error::TensorError;
+
 /// The main Tensor struct 
 /// with data and shape order by [... , z, y, x]
+///
+/// With `serde` feature, tensor can be serialized with its shape and data,
+/// deserialization fails if data length does not match shape
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawTensor<T>"))]
 pub struct Tensor<T>{
     data: Vec<T>,
     //..., z, y, x
     shape: Vec<u32>,
 }
 
+//unvalidated form of tensor, deserialized before checking data against shape
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawTensor<T>{
+    data: Vec<T>,
+    shape: Vec<u32>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<RawTensor<T>> for Tensor<T>{
+    type Error = TensorError;
+
+    fn try_from(raw: RawTensor<T>) -> Result<Self, TensorError>{
+        let expected = raw.shape.iter().try_fold(1usize, |acc, &dim| acc.checked_mul(dim as usize));
+        if expected != Some(raw.data.len()){
+            return Err(TensorError::DataLengthMismatch{ expected: expected.unwrap_or(usize::MAX), got: raw.data.len() });
+        }
+
+        Ok(Self{
+            data: raw.data,
+            shape: raw.shape,
+        })
+    }
+}
+
 impl<T: Default + Clone> Tensor<T>{
     /// Creates a new tensor with shape
     /// and default values of each element
@@ -47,11 +79,28 @@ impl<T: Default + Clone> Tensor<T>{
     /// assert_eq!(a.get_data(), &vec!{1.0, 2.0, 3.0, 4.0});
     /// ```
     pub fn from_data(_data: &[T], _shape: &[u32]) -> Option<Self>{
-        if _shape.iter().product::<u32>() as usize != _data.len(){
-            return None;
+        Self::from_data_checked(_data, _shape).ok()
+    }
+
+    /// Creates a new tensor from data
+    /// with certain size, or error
+    /// if data does not fit in shape
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    ///
+    /// let a = Tensor::from_data_checked(&[1.0, 2.0, 3.0], &[2, 2]);
+    ///
+    /// assert_eq!(a.err(), Some(TensorError::DataLengthMismatch{ expected: 4, got: 3 }));
+    /// ```
+    pub fn from_data_checked(_data: &[T], _shape: &[u32]) -> Result<Self, TensorError>{
+        let expected = _shape.iter().product::<u32>() as usize;
+        if expected != _data.len(){
+            return Err(TensorError::DataLengthMismatch{ expected, got: _data.len() });
         }
 
-        Some(Self{
+        Ok(Self{
             data: _data.to_vec(),
             shape: _shape.to_vec(),
         })
@@ -80,6 +129,46 @@ impl<T: Default + Clone> Tensor<T>{
         }
     }
 
+    /// Creates a new tensor with shape
+    /// filled with default value, so zeros for numbers
+    /// Empty shape creates scalar tensor with one element
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// //a =
+    /// //[0.0, 0.0, 0.0]
+    /// //[0.0, 0.0, 0.0]
+    /// let a: Tensor<f32> = Tensor::zeros(&[2, 3]);
+    ///
+    /// assert_eq!(a.get_data(), &vec!{0.0; 6});
+    /// assert_eq!(a.get_shape(), &vec!{2, 3});
+    /// ```
+    pub fn zeros(shape: &[u32]) -> Self{
+        Self::fill(T::default(), shape)
+    }
+
+    /// Creates a new tensor with shape
+    /// filled with one value, same as fill
+    /// Empty shape creates scalar tensor with one element
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// //a =
+    /// //[7.0, 7.0, 7.0]
+    /// //[7.0, 7.0, 7.0]
+    /// let a: Tensor<f32> = Tensor::full(7.0, &[2, 3]);
+    /// let scalar: Tensor<f32> = Tensor::full(7.0, &[]);
+    ///
+    /// assert_eq!(a.get_data(), &vec!{7.0; 6});
+    /// assert_eq!(a.get_shape(), &vec!{2, 3});
+    /// assert_eq!(scalar.get_data(), &vec!{7.0});
+    /// ```
+    pub fn full(value: T, shape: &[u32]) -> Self{
+        Self::fill(value, shape)
+    }
+
     /// Returns reference to data in tensor
     /// 
     /// # Example
@@ -185,6 +274,208 @@ impl<T: Default + Clone> Tensor<T>{
         self.shape = new_shape.to_vec();
     }
 
+    /// Returns tensor with same data and new shape
+    /// None if new shape does not fit number of elements
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]).unwrap();
+    ///
+    /// let b: Tensor<f32> = a.reshape(&[3, 2]).unwrap();
+    ///
+    /// assert_eq!(b.get_data(), a.get_data());
+    /// assert_eq!(b.get_shape(), &vec!{3, 2});
+    ///
+    /// assert!(a.reshape(&[4, 2]).is_none());
+    /// ```
+    pub fn reshape(&self, new_shape: &[u32]) -> Option<Tensor<T>>{
+        self.reshape_checked(new_shape).ok()
+    }
+
+    /// Returns tensor with same data and new shape
+    /// or error if new shape does not fit number of elements
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::new(&[2, 3]);
+    ///
+    /// let b = a.reshape_checked(&[4, 2]);
+    ///
+    /// assert_eq!(b.err(), Some(TensorError::DataLengthMismatch{ expected: 8, got: 6 }));
+    /// ```
+    pub fn reshape_checked(&self, new_shape: &[u32]) -> Result<Tensor<T>, TensorError>{
+        Tensor::from_data_checked(&self.data, new_shape)
+    }
+
+    /// Returns 1D tensor with all elements in row-major order
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+    ///
+    /// let b: Tensor<f32> = a.flatten();
+    ///
+    /// assert_eq!(b.get_data(), &vec!{1.0, 2.0, 3.0, 4.0});
+    /// assert_eq!(b.get_shape(), &vec!{4});
+    /// ```
+    pub fn flatten(&self) -> Tensor<T>{
+        Self{
+            data: self.data.clone(),
+            shape: vec!{self.data.len() as u32},
+        }
+    }
+
+    /// Returns tensor without dimensions of size 1
+    /// or shape [1] if all dimensions are removed
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::new(&[2, 1, 3]);
+    ///
+    /// let b: Tensor<f32> = a.squeeze();
+    ///
+    /// assert_eq!(b.get_shape(), &vec!{2, 3});
+    /// ```
+    pub fn squeeze(&self) -> Tensor<T>{
+        let mut new_shape: Vec<u32> = Vec::with_capacity(self.shape.len());
+        for i in 0..self.shape.len(){
+            if self.shape[i] != 1{
+                new_shape.push(self.shape[i]);
+            }
+        }
+        if new_shape.is_empty(){
+            new_shape.push(1);
+        }
+
+        Self{
+            data: self.data.clone(),
+            shape: new_shape,
+        }
+    }
+
+    /// Returns tensor with dimension of size 1 inserted on axis
+    /// None if axis is bigger than number of dimensions
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::new(&[2, 3]);
+    ///
+    /// let b: Tensor<f32> = a.unsqueeze(0).unwrap();
+    ///
+    /// assert_eq!(b.get_shape(), &vec!{1, 2, 3});
+    /// assert!(a.unsqueeze(3).is_none());
+    /// ```
+    pub fn unsqueeze(&self, axis: u32) -> Option<Tensor<T>>{
+        if axis as usize > self.shape.len(){
+            return None;
+        }
+
+        let mut new_shape: Vec<u32> = self.shape.clone();
+        new_shape.insert(axis as usize, 1);
+
+        Some(Self{
+            data: self.data.clone(),
+            shape: new_shape,
+        })
+    }
+
+    /// Merges first n axes into one,
+    /// returns merged tensor and removed dimensions to restore with split_leading
+    /// None if n is 0 or bigger than number of dimensions
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::new(&[2, 3, 4]);
+    ///
+    /// let (b, dims) = a.merge_leading(2).unwrap();
+    ///
+    /// assert_eq!(b.get_shape(), &vec!{6, 4});
+    /// assert_eq!(dims, vec!{2, 3});
+    /// ```
+    pub fn merge_leading(&self, n: u32) -> Option<(Tensor<T>, Vec<u32>)>{
+        if n == 0 || n as usize > self.shape.len(){
+            return None;
+        }
+
+        let dims: Vec<u32> = self.shape[..n as usize].to_vec();
+        let mut new_shape: Vec<u32> = vec!{dims.iter().product()};
+        new_shape.extend_from_slice(&self.shape[n as usize..]);
+
+        Some((Self{
+            data: self.data.clone(),
+            shape: new_shape,
+        }, dims))
+    }
+
+    /// Splits first axis into dims, reverse of merge_leading
+    /// None if tensor has no dimensions or product of dims is not size of first axis
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::new(&[6, 4]);
+    ///
+    /// let b: Tensor<f32> = a.split_leading(&[2, 3]).unwrap();
+    ///
+    /// assert_eq!(b.get_shape(), &vec!{2, 3, 4});
+    /// ```
+    pub fn split_leading(&self, dims: &[u32]) -> Option<Tensor<T>>{
+        if self.shape.is_empty() || dims.iter().product::<u32>() != self.shape[0]{
+            return None;
+        }
+
+        let mut new_shape: Vec<u32> = dims.to_vec();
+        new_shape.extend_from_slice(&self.shape[1..]);
+
+        Some(Self{
+            data: self.data.clone(),
+            shape: new_shape,
+        })
+    }
+
+    /// Returns tensor with f applied to each element,
+    /// shape stays the same
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::from_data(&[-1.0, 2.0, -3.0, 4.0], &[2, 2]).unwrap();
+    ///
+    /// let b: Tensor<f32> = a.map(|x| x.abs());
+    ///
+    /// assert_eq!(b.get_data(), &vec!{1.0, 2.0, 3.0, 4.0});
+    /// assert_eq!(b.get_shape(), &vec!{2, 2});
+    /// ```
+    pub fn map(&self, f: impl Fn(&T) -> T) -> Tensor<T>{
+        Self{
+            data: self.data.iter().map(f).collect(),
+            shape: self.shape.clone(),
+        }
+    }
+
+    /// Applies f to each element in place, without reallocating data
+    ///
+    /// !Mutates a tensor
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let mut a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+    ///
+    /// a.map_inplace(|x| *x *= 2.0);
+    ///
+    /// assert_eq!(a.get_data(), &vec!{2.0, 4.0, 6.0, 8.0});
+    /// ```
+    pub fn map_inplace(&mut self, f: impl FnMut(&mut T)){
+        self.data.iter_mut().for_each(f);
+    }
+
     /// Change the data of tensor if the new data has length equal to current data length
     ///
     /// # Example
@@ -203,6 +494,56 @@ impl<T: Default + Clone> Tensor<T>{
 
         self.data = new_data.to_vec();
     }
+
+    /// Change the shape of tensor allowing different element count.
+    /// Data is truncated or padded with fill in row-major order.
+    /// Returns false if new_shape is empty
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let mut a: Tensor<f32> = Tensor::fill(1.0, &[2, 2]);
+    ///
+    /// //a =
+    /// //[1.0, 1.0, 1.0]
+    /// //[1.0, 0.0, 0.0]
+    /// a.resize(&[2, 3], 0.0);
+    ///
+    /// assert_eq!(a.get_data(), &vec!{1.0, 1.0, 1.0, 1.0, 0.0, 0.0});
+    /// assert_eq!(a.get_shape(), &vec!{2, 3});
+    /// ```
+    pub fn resize(&mut self, new_shape: &[u32], fill: T) -> bool{
+        if new_shape.is_empty(){
+            return false;
+        }
+
+        let shape_prod: u32 = new_shape.iter().product();
+
+        self.data.resize(shape_prod as usize, fill);
+        self.shape = new_shape.to_vec();
+
+        true
+    }
+}
+impl<T: Default + Clone + From<u8>> Tensor<T>{
+    /// Creates a new tensor with shape
+    /// filled with ones
+    /// Empty shape creates scalar tensor with one element
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// //a =
+    /// //[1.0, 1.0, 1.0]
+    /// //[1.0, 1.0, 1.0]
+    /// let a: Tensor<f32> = Tensor::ones(&[2, 3]);
+    ///
+    /// assert_eq!(a.get_data(), &vec!{1.0; 6});
+    /// assert_eq!(a.get_shape(), &vec!{2, 3});
+    /// ```
+    pub fn ones(shape: &[u32]) -> Self{
+        Self::fill(T::from(1), shape)
+    }
 }
 impl<T> Tensor<T>{
     /// returns an element on position
@@ -218,15 +559,28 @@ impl<T> Tensor<T>{
     /// assert_eq!(b, &1.0);
     /// ```
     pub fn value(&self, pos: &[u32]) -> Option<&T>{
-        let self_dimensions = self.shape.len();
-        let selector_dimensions = pos.len();
-        if self_dimensions - selector_dimensions != 0{
-            return None;
+        self.value_checked(pos).ok()
+    }
+
+    /// Returns reference to value on position
+    /// or error if position has wrong number of dimensions or is out of bounds
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::new(&[2, 2]);
+    ///
+    /// assert_eq!(a.value_checked(&[1, 1]), Ok(&0.0));
+    /// assert_eq!(a.value_checked(&[2, 0]), Err(TensorError::IndexOutOfBounds{ index: vec!{2, 0}, shape: vec!{2, 2} }));
+    /// ```
+    pub fn value_checked(&self, pos: &[u32]) -> Result<&T, TensorError>{
+        if self.shape.len() != pos.len(){
+            return Err(TensorError::RankMismatch{ expected: self.shape.len(), got: pos.len() });
         }
         
         for i in 0..pos.len(){
-            if pos[i] >= *self.shape.get(i).unwrap(){
-                return None;
+            if pos[i] >= self.shape[i]{
+                return Err(TensorError::IndexOutOfBounds{ index: pos.to_vec(), shape: self.shape.clone() });
             }
         }
         let mut index = 0;
@@ -236,7 +590,7 @@ impl<T> Tensor<T>{
             stride *= self.shape[i];
         }
 
-        Some(&self.data[index as usize])
+        Ok(&self.data[index as usize])
     }
     /// changes an element on position
     ///
@@ -288,6 +642,199 @@ impl<T> Tensor<T>{
     pub fn idx_to_global(&self, idx: u32) -> Vec<u32>{
         idx_to_global(idx, &self.shape)
     }
+
+    /// returns row-major strides of tensor,
+    /// number of elements to skip to move by one on each axis
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::new(&[2, 3, 4]);
+    ///
+    /// assert_eq!(a.strides(), vec!{12, 4, 1});
+    /// ```
+    pub fn strides(&self) -> Vec<u32>{
+        let mut strides: Vec<u32> = vec![1; self.shape.len()];
+
+        for i in (0..self.shape.len().saturating_sub(1)).rev(){
+            strides[i] = strides[i+1] * self.shape[i+1];
+        }
+
+        strides
+    }
+
+    /// checks if data of tensor is laid out contiguously in row-major order.
+    /// Tensor always owns its data, so for now it is always true
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::new(&[2, 3, 4]);
+    ///
+    /// assert!(a.is_contiguous());
+    /// ```
+    pub fn is_contiguous(&self) -> bool{
+        self.data.len() == self.numel()
+    }
+
+    /// returns iterator over elements of tensor with their position
+    /// in row-major order
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+    ///
+    /// let positions: Vec<(Vec<u32>, &f32)> = a.indexed_iter().collect();
+    ///
+    /// assert_eq!(positions[1], (vec!{0, 1}, &2.0));
+    /// assert_eq!(positions[2], (vec!{1, 0}, &3.0));
+    /// ```
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Vec<u32>, &T)>{
+        self.data.iter()
+            .enumerate()
+            .map(|(i, val)| (idx_to_global(i as u32, &self.shape), val))
+    }
+
+    /// number of elements in tensor, product of shape
+    /// Tensor with empty shape is a scalar with one element
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::new(&[2, 3, 4]);
+    ///
+    /// assert_eq!(a.numel(), 24);
+    /// ```
+    pub fn numel(&self) -> usize{
+        self.shape.iter().product::<u32>() as usize
+    }
+
+    /// checks if tensor has no elements,
+    /// so if any dimension of shape is 0
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::new(&[2, 0]);
+    /// let b: Tensor<f32> = Tensor::new(&[2, 1]);
+    ///
+    /// assert!(a.is_empty());
+    /// assert!(!b.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool{
+        self.numel() == 0
+    }
+
+    /// number of dimensions of tensor
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::new(&[2, 2, 2]);
+    ///
+    /// assert_eq!(a.rank(), 3);
+    /// ```
+    pub fn rank(&self) -> usize{
+        self.shape.len()
+    }
+
+    /// checks if tensor is a matrix, so has 2 dimensions
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::new(&[2, 3]);
+    ///
+    /// assert!(a.is_matrix());
+    /// ```
+    pub fn is_matrix(&self) -> bool{
+        self.rank() == 2
+    }
+
+    /// checks if tensor is a square matrix
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::new(&[3, 3]);
+    /// let b: Tensor<f32> = Tensor::new(&[2, 3]);
+    ///
+    /// assert!(a.is_square());
+    /// assert!(!b.is_square());
+    /// ```
+    pub fn is_square(&self) -> bool{
+        self.is_matrix() && self.shape[0] == self.shape[1]
+    }
+
+    /// Iterates over elements of tensor in row-major order
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let a: Tensor<f32> = Tensor::from_data(&[1.0, -2.0, 3.0, 4.0], &[2, 2]).unwrap();
+    ///
+    /// assert_eq!(a.iter().copied().sum::<f32>(), 6.0);
+    /// assert_eq!(a.iter().filter(|x| **x > 0.0).count(), 3);
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, T>{
+        self.data.iter()
+    }
+
+    /// Iterates over mutable elements of tensor in row-major order
+    ///
+    /// !Mutates a tensor
+    ///
+    /// # Example
+    /// ```
+    /// use flashlight_tensor::prelude::*;
+    /// let mut a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0], &[2, 2]).unwrap();
+    ///
+    /// a.iter_mut().for_each(|x| *x += 1.0);
+    ///
+    /// assert_eq!(a.get_data(), &vec!{2.0, 3.0, 4.0, 5.0});
+    /// ```
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T>{
+        self.data.iter_mut()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Tensor<T>{
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter{
+        self.data.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Tensor<T>{
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter{
+        self.data.iter_mut()
+    }
+}
+
+/// Consumes tensor, yielding its elements in row-major order
+///
+/// # Example
+/// ```
+/// use flashlight_tensor::prelude::*;
+/// let a: Tensor<i32> = Tensor::from_data(&[1, 2, 3, 4], &[2, 2]).unwrap();
+///
+/// let data: Vec<i32> = a.into_iter().collect();
+///
+/// assert_eq!(data, vec!{1, 2, 3, 4});
+/// ```
+impl<T> IntoIterator for Tensor<T>{
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter{
+        self.data.into_iter()
+    }
 }
 
 /// change linear id into global id based on shape
@@ -319,3 +866,71 @@ pub fn idx_to_global(idx: u32, shape: &[u32]) -> Vec<u32>{
 
     output_vec
 }
+
+/// split shape around axis into (outer size, axis length, inner size),
+/// so element on position i of axis is at outer_id * length * inner + i * inner + inner_id
+/// None if axis is out of range
+///
+/// # Example
+/// ```
+/// use flashlight_tensor::prelude::*;
+///
+/// let split = split_axis(&[2, 3, 4], 1).unwrap();
+///
+/// assert_eq!(split, (2, 3, 4));
+/// ```
+pub fn split_axis(shape: &[u32], axis: u32) -> Option<(u32, u32, u32)>{
+    if axis as usize >= shape.len(){
+        return None;
+    }
+
+    let outer: u32 = shape[..axis as usize].iter().product();
+    let inner: u32 = shape[axis as usize + 1..].iter().product();
+
+    Some((outer, shape[axis as usize], inner))
+}
+
+/// Prints tensor as nested brackets, like [[1, 2], [3, 4]] for matrix
+/// tensor with shape [1] prints as bare value
+///
+/// # Example
+/// ```
+/// use flashlight_tensor::prelude::*;
+///
+/// let a: Tensor<f32> = Tensor::from_data(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0], &[2, 2, 2]).unwrap();
+///
+/// assert_eq!(a.to_string(), "[[[1, 2], [3, 4]], [[5, 6], [7, 8]]]");
+/// ```
+impl<T: std::fmt::Display> std::fmt::Display for Tensor<T>{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result{
+        if (self.shape.is_empty() || self.shape == [1]) && let Some(value) = self.data.first(){
+            return write!(f, "{}", value);
+        }
+
+        fmt_nested(f, &self.data, &self.shape)
+    }
+}
+
+fn fmt_nested<T: std::fmt::Display>(f: &mut std::fmt::Formatter<'_>, data: &[T], shape: &[u32]) -> std::fmt::Result{
+    write!(f, "[")?;
+
+    if shape.len() <= 1{
+        for (i, value) in data.iter().enumerate(){
+            if i > 0{
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", value)?;
+        }
+    }
+    else{
+        let chunk = shape[1..].iter().product::<u32>() as usize;
+        for i in 0..shape[0] as usize{
+            if i > 0{
+                write!(f, ", ")?;
+            }
+            fmt_nested(f, &data[i*chunk..(i+1)*chunk], &shape[1..])?;
+        }
+    }
+
+    write!(f, "]")
+}